@@ -0,0 +1,380 @@
+//! Fetch CCADB reports and generate the code of the `webpki-roots` and `webpki-root-certs`
+//! crates from them.
+//!
+//! Run `ccadb-gen help` for usage. Fetching reports requires the `blocking` feature; everything
+//! else also works offline from a snapshot without it.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use chrono::{Duration, NaiveDate, Utc};
+#[cfg(feature = "blocking")]
+use webpki_ccadb::blocking::CcadbClient;
+use webpki_ccadb::codegen::{Crate, Generator};
+use webpki_ccadb::{CertificateMetadata, Snapshot, TrustPolicy};
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("fetch") => Options::parse(args, &[]).and_then(fetch),
+        Some("constrain") => Options::parse(args, &[]).and_then(constrain),
+        Some("generate") => Options::parse(args, &["crate"]).and_then(generate),
+        Some("check") => Options::parse(args, &["crate"]).and_then(check),
+        Some("diff") => Options::parse(args, &["old", "new"]).and_then(diff),
+        Some("expiry") => Options::parse(args, &[]).and_then(expiry),
+        Some("help" | "-h" | "--help") => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Some(command) => Err(format!("unknown command {command:?}")),
+        None => Err("missing command".to_string()),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Write a snapshot of the CCADB report to `--snapshot`, or the report to `--output` or stdout.
+///
+/// A new snapshot keeps the extra name constraints of the snapshot it replaces, and adds any
+/// `--name-constraints`.
+fn fetch(options: Options) -> Result<ExitCode, String> {
+    let snapshot = options.fetch_snapshot()?;
+    match (&options.snapshot, &options.output) {
+        (Some(dir), _) => {
+            let mut snapshot = snapshot;
+            if dir.join(Snapshot::MANIFEST_FILE).is_file() {
+                for (fingerprint, der) in load(dir)?.name_constraints() {
+                    snapshot = snapshot
+                        .with_name_constraints(fingerprint, der.clone())
+                        .map_err(|err| err.to_string())?;
+                }
+            }
+            write(&options.name_constraints(snapshot)?, dir)?;
+        }
+        (None, _) if !options.name_constraints.is_empty() => {
+            return Err("--name-constraints requires --snapshot".to_string())
+        }
+        (None, Some(path)) => {
+            fs::write(path, snapshot.csv()).map_err(|err| format!("{}: {err}", path.display()))?
+        }
+        (None, None) => print!("{}", snapshot.csv()),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Add the `--name-constraints` to the snapshot at `--snapshot`.
+fn constrain(options: Options) -> Result<ExitCode, String> {
+    let dir = options
+        .snapshot
+        .as_ref()
+        .ok_or("constrain requires --snapshot")?;
+    write(&options.name_constraints(load(dir)?)?, dir)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Write the generated code of the crates.
+fn generate(options: Options) -> Result<ExitCode, String> {
+    let generator = options.generator()?;
+    for krate in options.crates()? {
+        let dir = options.crate_dir(krate)?;
+        for file in generator.generate(krate) {
+            let path = dir.join(file.path);
+            file.write(&dir)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            eprintln!("wrote {}", path.display());
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Check that the checked-in code of the crates matches the generated code.
+fn check(options: Options) -> Result<ExitCode, String> {
+    let generator = options.generator()?;
+    let mut fresh = true;
+    for krate in options.crates()? {
+        let dir = options.crate_dir(krate)?;
+        for file in generator.generate(krate) {
+            let path = dir.join(file.path);
+            if !file
+                .is_fresh(&dir)
+                .map_err(|err| format!("{}: {err}", path.display()))?
+            {
+                eprintln!("{} is out of date", path.display());
+                fresh = false;
+            }
+        }
+    }
+
+    match fresh {
+        true => Ok(ExitCode::SUCCESS),
+        false => Ok(ExitCode::FAILURE),
+    }
+}
+
+/// Print the changes to the roots trusted for TLS between two snapshots, as `--format`.
+fn diff(options: Options) -> Result<ExitCode, String> {
+    let old = tls_roots(&load(Path::new(&options.positional[0]))?)?;
+    let new = tls_roots(&load(Path::new(&options.positional[1]))?)?;
+
+    let diff = webpki_ccadb::diff(&old, &new);
+    match options.format.as_deref() {
+        None | Some("markdown") => print!("{}", diff.to_markdown()),
+        Some("json") => println!("{}", diff.to_json()),
+        Some(format) => return Err(format!("unknown format {format:?}")),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Print the roots trusted for TLS that expire within `--days` of `--at`.
+///
+/// Fails if any root has already expired at `--at`, with `--fail-on-expired`.
+fn expiry(options: Options) -> Result<ExitCode, String> {
+    let roots = tls_roots(&options.snapshot()?)?;
+
+    let at = match &options.at {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("invalid date {date:?}"))?
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc(),
+        None => Utc::now(),
+    };
+    let days = match &options.days {
+        Some(days) => days
+            .parse()
+            .map_err(|_| format!("invalid number of days {days:?}"))?,
+        None => 365,
+    };
+
+    let expiring = webpki_ccadb::expiring_roots(&roots, at, Duration::days(days))
+        .map_err(|err| err.to_string())?;
+    let mut expired = false;
+    for root in &expiring {
+        match root.is_expired_at(at) {
+            true => {
+                println!("expired:  {root}");
+                expired = true;
+            }
+            false => println!("expiring: {root}"),
+        }
+    }
+
+    match expired && options.fail_on_expired {
+        true => Ok(ExitCode::FAILURE),
+        false => Ok(ExitCode::SUCCESS),
+    }
+}
+
+/// Returns the roots of `snapshot` trusted for TLS as of the date it was fetched, as selected
+/// for generated code.
+fn tls_roots(snapshot: &Snapshot) -> Result<BTreeMap<String, CertificateMetadata>, String> {
+    let metadata = snapshot.metadata().map_err(|err| err.to_string())?;
+    TrustPolicy::default()
+        .at(snapshot.fetched_at().date_naive())
+        .apply(metadata)
+        .map_err(|err| err.to_string())
+}
+
+/// Load the snapshot in the directory `path`.
+fn load(path: &Path) -> Result<Snapshot, String> {
+    Snapshot::read(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Write `snapshot` to the directory `dir`.
+fn write(snapshot: &Snapshot, dir: &Path) -> Result<(), String> {
+    snapshot
+        .write(dir)
+        .map_err(|err| format!("{}: {err}", dir.display()))?;
+    eprintln!(
+        "wrote snapshot {} to {}",
+        snapshot.sha256_hex(),
+        dir.display()
+    );
+    Ok(())
+}
+
+#[derive(Default)]
+struct Options {
+    positional: Vec<String>,
+    snapshot: Option<PathBuf>,
+    dir: Option<PathBuf>,
+    output: Option<PathBuf>,
+    #[cfg_attr(not(feature = "blocking"), allow(dead_code))]
+    base_url: Option<String>,
+    format: Option<String>,
+    at: Option<String>,
+    days: Option<String>,
+    fail_on_expired: bool,
+    name_constraints: Vec<String>,
+}
+
+impl Options {
+    /// Parse the options of a command that takes the given positional arguments.
+    fn parse(mut args: impl Iterator<Item = String>, positional: &[&str]) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {arg}"))
+            };
+            match arg.as_str() {
+                "--snapshot" => options.snapshot = Some(value()?.into()),
+                "--dir" => options.dir = Some(value()?.into()),
+                "--output" => options.output = Some(value()?.into()),
+                "--base-url" => options.base_url = Some(value()?),
+                "--format" => options.format = Some(value()?),
+                "--at" => options.at = Some(value()?),
+                "--days" => options.days = Some(value()?),
+                "--fail-on-expired" => options.fail_on_expired = true,
+                "--name-constraints" => options.name_constraints.push(value()?),
+                _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+                _ => options.positional.push(arg),
+            }
+        }
+
+        match options.positional.len() == positional.len() {
+            true => Ok(options),
+            false => Err(format!("expected arguments: {positional:?}")),
+        }
+    }
+
+    /// Returns the crate named by the first argument, or every crate for "all".
+    ///
+    /// Fails for "all" with `--dir`, which can only be the directory of one crate.
+    fn crates(&self) -> Result<Vec<Crate>, String> {
+        match self.positional[0].as_str() {
+            "all" if self.dir.is_some() => Err("--dir can not be used with all".to_string()),
+            "all" => Ok(Crate::ALL.to_vec()),
+            name => Crate::from_name(name)
+                .map(|krate| vec![krate])
+                .ok_or_else(|| format!("unknown crate {name:?}")),
+        }
+    }
+
+    /// Returns a generator for the snapshot at `--snapshot`.
+    ///
+    /// Generated code records the snapshot it was generated from, so it must be generated from
+    /// a snapshot directory that can be kept, rather than a freshly fetched report.
+    fn generator(&self) -> Result<Generator, String> {
+        let path = self
+            .snapshot
+            .as_ref()
+            .ok_or("generate and check require --snapshot")?;
+        Generator::new(&load(path)?).map_err(|err| err.to_string())
+    }
+
+    /// Returns `snapshot` with the `--name-constraints`, given as `FINGERPRINT=DER` with the DER
+    /// in hex, or read from a file with `FINGERPRINT=@FILE`.
+    fn name_constraints(&self, mut snapshot: Snapshot) -> Result<Snapshot, String> {
+        for overlay in &self.name_constraints {
+            let (fingerprint, der) = overlay
+                .split_once('=')
+                .ok_or_else(|| format!("invalid --name-constraints {overlay:?}"))?;
+            let der = match der.strip_prefix('@') {
+                Some(path) => fs::read(path).map_err(|err| format!("{path}: {err}"))?,
+                None => hex::decode(der)
+                    .map_err(|_| format!("invalid --name-constraints {overlay:?}"))?,
+            };
+            snapshot = snapshot
+                .with_name_constraints(fingerprint, der)
+                .map_err(|err| err.to_string())?;
+        }
+        Ok(snapshot)
+    }
+
+    /// Returns the snapshot at `--snapshot`, or a freshly fetched report.
+    fn snapshot(&self) -> Result<Snapshot, String> {
+        match &self.snapshot {
+            Some(path) => load(path),
+            None => self.fetch_snapshot(),
+        }
+    }
+
+    /// Fetch a snapshot of the report.
+    #[cfg(feature = "blocking")]
+    fn fetch_snapshot(&self) -> Result<Snapshot, String> {
+        let client = self.client()?;
+        eprintln!("fetching {}...", client.report_url());
+        client.fetch_snapshot().map_err(|err| err.to_string())
+    }
+
+    /// Fails, as fetching requires the `blocking` feature.
+    #[cfg(not(feature = "blocking"))]
+    fn fetch_snapshot(&self) -> Result<Snapshot, String> {
+        Err("fetching the report requires the `blocking` feature".to_string())
+    }
+
+    /// Returns `--dir`, or the directory of `krate` in the workspace containing the current
+    /// directory.
+    fn crate_dir(&self, krate: Crate) -> Result<PathBuf, String> {
+        if let Some(dir) = &self.dir {
+            return Ok(dir.clone());
+        }
+
+        let cwd = env::current_dir().map_err(|err| err.to_string())?;
+        cwd.ancestors()
+            .map(|dir| dir.join(krate.name()))
+            .find(|dir| dir.join("Cargo.toml").is_file())
+            .ok_or_else(|| {
+                let name = krate.name();
+                format!("no {name} crate found from {}, use --dir", cwd.display())
+            })
+    }
+
+    #[cfg(feature = "blocking")]
+    fn client(&self) -> Result<CcadbClient, String> {
+        let mut builder = webpki_ccadb::CcadbClient::builder();
+        if let Some(base_url) = &self.base_url {
+            builder = builder.base_url(base_url);
+        }
+        builder.build_blocking().map_err(|err| err.to_string())
+    }
+}
+
+const USAGE: &str = "\
+usage: ccadb-gen <command> [options]
+
+commands:
+  fetch                       Fetch the CCADB report, writing it to stdout.
+  constrain                   Add --name-constraints to the snapshot in --snapshot.
+  generate <crate>            Write the generated code of <crate> from --snapshot.
+  check <crate>               Exit with an error if the code of <crate> is not the code
+                              generated from --snapshot.
+  diff <old> <new>            List the TLS roots added, removed and modified between two
+                              snapshots.
+  expiry                      List the TLS roots that expire soon, or have expired.
+  help                        Print this message.
+
+<crate> is webpki-roots, webpki-root-certs, or all (to generate both from one report).
+
+A snapshot is a directory written by `fetch --snapshot`. Roots are selected as of the date
+the snapshot was fetched. A snapshot also records extra name constraints for some roots, which
+`fetch --snapshot` keeps from the snapshot it replaces.
+
+options:
+  --snapshot DIR    With fetch, write a snapshot to the directory DIR. Otherwise, use the
+                    snapshot in DIR (required by generate and check; expiry fetches the
+                    report without it).
+  --output FILE     With fetch, write the report to FILE.
+  --dir DIR         The crate directory (defaults to the crate in the workspace containing
+                    the current directory). Can not be used with all.
+  --base-url URL    Fetch from a different CCADB server.
+  --format FORMAT   With diff, print markdown (the default) or json.
+  --at DATE         With expiry, the YYYY-MM-DD date to check at (defaults to now).
+  --days N          With expiry, list roots expiring within N days of --at (default 365).
+  --fail-on-expired With expiry, exit with an error if any root has expired at --at.
+  --name-constraints FINGERPRINT=DER
+                    With fetch --snapshot and constrain, record in the snapshot that the
+                    root with the hex SHA-256 FINGERPRINT is also limited to the names
+                    permitted by DER: name constraints in the DER form of webpki trust
+                    anchors, in hex or read from a file with @FILE. Can be repeated.
+";
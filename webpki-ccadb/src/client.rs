@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::Utc;
+use pki_types::pem::PemObject;
+use pki_types::CertificateDer;
+
+use crate::{read_ccadb_csv, CertificateMetadata, Error, Snapshot, TrustPolicy};
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+/// A client for fetching root certificate data from the CCADB server.
+///
+/// Use [`CcadbClient::builder()`] to point the client at a different server (e.g. an internal
+/// mirror), or to change the roots trusted for the download.
+#[derive(Debug, Clone)]
+pub struct CcadbClient {
+    http: reqwest::Client,
+    report_url: reqwest::Url,
+}
+
+impl CcadbClient {
+    /// Returns a builder for configuring a client.
+    pub fn builder() -> CcadbClientBuilder {
+        CcadbClientBuilder::default()
+    }
+
+    /// Fetch root certificate data from the configured CCADB server.
+    ///
+    /// Returns an ordered BTreeMap of the root certificates trusted for TLS, keyed by the SHA256
+    /// fingerprint of the certificate, or an error if the report can not be fetched or parsed,
+    /// or if there are any duplicate fingerprints.
+    pub async fn fetch_roots(&self) -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+        self.fetch_roots_with_policy(&TrustPolicy::default()).await
+    }
+
+    /// Fetch root certificate data from the configured CCADB server, filtered by `policy`.
+    ///
+    /// Returns an ordered BTreeMap of the root certificates trusted under `policy`, keyed by
+    /// the SHA256 fingerprint of the certificate, or an error if the report can not be fetched
+    /// or parsed, or if there are any duplicate fingerprints.
+    pub async fn fetch_roots_with_policy(
+        &self,
+        policy: &TrustPolicy,
+    ) -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+        policy.apply(self.fetch_metadata().await?)
+    }
+
+    /// Fetch every certificate in the report from the configured CCADB server, without any
+    /// filtering.
+    pub async fn fetch_metadata(&self) -> Result<Vec<CertificateMetadata>, Error> {
+        read_ccadb_csv(self.fetch_csv().await?.as_bytes())
+    }
+
+    /// Fetch a [`Snapshot`] of the CSV report from the configured CCADB server.
+    pub async fn fetch_snapshot(&self) -> Result<Snapshot, Error> {
+        let csv = self.fetch_csv().await?;
+        Ok(Snapshot::new(self.report_url.as_str(), Utc::now(), csv))
+    }
+
+    /// Fetch the raw CSV report from the configured CCADB server.
+    pub async fn fetch_csv(&self) -> Result<String, Error> {
+        let req = self.http.get(self.report_url.clone()).build()?;
+        Ok(self
+            .http
+            .execute(req)
+            .await?
+            .error_for_status()?
+            .text()
+            .await?)
+    }
+
+    /// Returns the URL the CSV report is fetched from.
+    pub fn report_url(&self) -> &str {
+        self.report_url.as_str()
+    }
+}
+
+/// A builder for [`CcadbClient`].
+#[derive(Debug, Clone)]
+pub struct CcadbClientBuilder {
+    base_url: String,
+    trust_roots: Vec<CertificateDer<'static>>,
+    user_agent: String,
+    timeout: Option<Duration>,
+    proxy: Option<reqwest::Proxy>,
+}
+
+impl CcadbClientBuilder {
+    /// Set the base URL of the CCADB server.
+    ///
+    /// The report path is resolved relative to this URL, so a base URL with a path component
+    /// should end in `/`. Defaults to [`DEFAULT_BASE_URL`].
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Set the root certificates trusted when connecting to the CCADB server.
+    ///
+    /// These replace the default pinned root (and any platform roots): only the given
+    /// certificates are trusted.
+    pub fn trust_roots(mut self, roots: impl IntoIterator<Item = CertificateDer<'static>>) -> Self {
+        self.trust_roots = roots.into_iter().collect();
+        self
+    }
+
+    /// Set the `User-Agent` header sent to the CCADB server.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Set a timeout for the whole request, from connecting until the report has been read.
+    ///
+    /// Defaults to no timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests to the CCADB server through the given proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Build the client, returning an error if the base URL or trust roots are invalid.
+    pub fn build(self) -> Result<CcadbClient, Error> {
+        let config = self.config()?;
+        let mut http = reqwest::Client::builder()
+            .user_agent(config.user_agent)
+            .tls_certs_only(config.trust_roots);
+        if let Some(timeout) = config.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(proxy) = config.proxy {
+            http = http.proxy(proxy);
+        }
+
+        Ok(CcadbClient {
+            http: http.build()?,
+            report_url: config.report_url,
+        })
+    }
+
+    /// Validate the configuration, for building a client with either transport.
+    fn config(self) -> Result<Config, Error> {
+        let report_url = reqwest::Url::parse(&self.base_url)
+            .and_then(|base| base.join(MOZILLA_REPORT_PATH))
+            .map_err(|_| Error::InvalidUrl(self.base_url.clone()))?;
+        let trust_roots = self
+            .trust_roots
+            .iter()
+            .map(|root| reqwest::Certificate::from_der(root))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Config {
+            report_url,
+            trust_roots,
+            user_agent: self.user_agent,
+            timeout: self.timeout,
+            proxy: self.proxy,
+        })
+    }
+}
+
+/// The validated configuration of a client, applied to the HTTP client of its transport.
+struct Config {
+    report_url: reqwest::Url,
+    trust_roots: Vec<reqwest::Certificate>,
+    user_agent: String,
+    timeout: Option<Duration>,
+    proxy: Option<reqwest::Proxy>,
+}
+
+impl Default for CcadbClientBuilder {
+    fn default() -> Self {
+        // By default only trust the CA certificate expected to be the root of trust for the
+        // CCADB server.
+        //
+        // If we see Unknown CA TLS validation failures from the Reqwest client in the future it
+        // likely indicates that the upstream service has changed certificate authorities. In this
+        // case the vendored root CA will need to be updated. You can find the current root in use
+        // with Chrome by:
+        //  1. Navigating to `https://ccadb-public.secure.force.com/mozilla/`
+        //  2. Clicking the lock icon.
+        //  3. Clicking "Connection is secure"
+        //  4. Clicking "Certificate is valid"
+        //  5. Clicking the "Details" tab.
+        //  6. Selecting the topmost "System Trust" entry.
+        //  7. Clicking "Export..." and saving the certificate to `webpki-roots/webpki-ccadb/src/data/`.
+        //  8. Committing the updated .pem root CA, and updating the `include_bytes!` path.
+        let root = CertificateDer::from_pem_slice(include_bytes!("data/DigiCertGlobalRootCA.pem"))
+            .expect("invalid vendored CCADB root");
+
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            trust_roots: vec![root],
+            user_agent: format!("webpki-ccadb/v{}", env!("CARGO_PKG_VERSION")),
+            timeout: None,
+            proxy: None,
+        }
+    }
+}
+
+/// The default base URL of the CCADB server.
+pub const DEFAULT_BASE_URL: &str = "https://ccadb.my.salesforce-sites.com/";
+
+/// The path of Mozilla's included CA certificate report, relative to the base URL.
+const MOZILLA_REPORT_PATH: &str = "mozilla/IncludedCACertificateReportPEMCSV";
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{SocketAddr, TcpListener};
+    use std::thread::{self, JoinHandle};
+
+    use super::*;
+
+    #[test]
+    fn test_report_url() {
+        let client = CcadbClient::builder().build().unwrap();
+        assert_eq!(
+            client.report_url(),
+            "https://ccadb.my.salesforce-sites.com/mozilla/IncludedCACertificateReportPEMCSV"
+        );
+
+        let client = CcadbClient::builder()
+            .base_url("https://mirror.example.com/ccadb/")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.report_url(),
+            "https://mirror.example.com/ccadb/mozilla/IncludedCACertificateReportPEMCSV"
+        );
+
+        assert!(matches!(
+            CcadbClient::builder().base_url("not a url").build(),
+            Err(Error::InvalidUrl(url)) if url == "not a url"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_roots_from_mirror() {
+        let (addr, server) = serve_csv_once();
+        let client = CcadbClient::builder()
+            .base_url(format!("http://{addr}/"))
+            .user_agent("ccadb-test")
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap();
+        let roots = client.fetch_roots().await.unwrap();
+        assert_eq!(roots.keys().collect::<Vec<_>>(), vec!["AA"]);
+
+        let (request_line, user_agent) = server.join().unwrap();
+        assert_eq!(
+            request_line,
+            "GET /mozilla/IncludedCACertificateReportPEMCSV HTTP/1.1\r\n"
+        );
+        assert_eq!(user_agent, "ccadb-test");
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_fetch_roots_blocking_from_mirror() {
+        let (addr, server) = serve_csv_once();
+        let client = CcadbClient::builder()
+            .base_url(format!("http://{addr}/"))
+            .user_agent("ccadb-test")
+            .build_blocking()
+            .unwrap();
+        let roots = client.fetch_roots().unwrap();
+        assert_eq!(roots.keys().collect::<Vec<_>>(), vec!["AA"]);
+
+        let (request_line, user_agent) = server.join().unwrap();
+        assert_eq!(
+            request_line,
+            "GET /mozilla/IncludedCACertificateReportPEMCSV HTTP/1.1\r\n"
+        );
+        assert_eq!(user_agent, "ccadb-test");
+    }
+
+    /// Serve `TEST_CSV` over plain HTTP for a single request, returning the request line and
+    /// user agent of the request from the server thread.
+    fn serve_csv_once() -> (SocketAddr, JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut user_agent = String::new();
+            for line in (&mut reader).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(": ") {
+                    if name.eq_ignore_ascii_case("user-agent") {
+                        user_agent = value.to_string();
+                    }
+                }
+            }
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{TEST_CSV}",
+                TEST_CSV.len()
+            )
+            .unwrap();
+            (request_line, user_agent)
+        });
+
+        (addr, server)
+    }
+
+    const TEST_CSV: &str = "\
+Common Name or Certificate Name,Certificate Serial Number,SHA-256 Fingerprint,Trust Bits,\
+Distrust for TLS After Date,Test Website - Revoked,Mozilla Applied Constraints,PEM Info
+Websites Root,01,AA,Websites,,,,''
+Email Root,02,BB,Email,,,,''
+";
+}
@@ -0,0 +1,83 @@
+//! A blocking CCADB client, for use outside of an async runtime.
+//!
+//! Requires the `blocking` cargo feature.
+
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+
+use super::CcadbClientBuilder;
+use crate::{read_ccadb_csv, CertificateMetadata, Error, Snapshot, TrustPolicy};
+
+/// A blocking client for fetching root certificate data from the CCADB server.
+///
+/// Use [`CcadbClientBuilder::build_blocking()`] to create one. This must not be used from
+/// within an async runtime; use [`crate::CcadbClient`] there instead.
+#[derive(Debug, Clone)]
+pub struct CcadbClient {
+    http: reqwest::blocking::Client,
+    report_url: reqwest::Url,
+}
+
+impl CcadbClient {
+    /// Fetch root certificate data from the configured CCADB server.
+    ///
+    /// See [`crate::CcadbClient::fetch_roots()`] for details.
+    pub fn fetch_roots(&self) -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+        self.fetch_roots_with_policy(&TrustPolicy::default())
+    }
+
+    /// Fetch root certificate data from the configured CCADB server, filtered by `policy`.
+    ///
+    /// See [`crate::CcadbClient::fetch_roots_with_policy()`] for details.
+    pub fn fetch_roots_with_policy(
+        &self,
+        policy: &TrustPolicy,
+    ) -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+        policy.apply(self.fetch_metadata()?)
+    }
+
+    /// Fetch every certificate in the report from the configured CCADB server, without any
+    /// filtering.
+    pub fn fetch_metadata(&self) -> Result<Vec<CertificateMetadata>, Error> {
+        read_ccadb_csv(self.fetch_csv()?.as_bytes())
+    }
+
+    /// Fetch a [`Snapshot`] of the CSV report from the configured CCADB server.
+    pub fn fetch_snapshot(&self) -> Result<Snapshot, Error> {
+        let csv = self.fetch_csv()?;
+        Ok(Snapshot::new(self.report_url.as_str(), Utc::now(), csv))
+    }
+
+    /// Fetch the raw CSV report from the configured CCADB server.
+    pub fn fetch_csv(&self) -> Result<String, Error> {
+        let req = self.http.get(self.report_url.clone()).build()?;
+        Ok(self.http.execute(req)?.error_for_status()?.text()?)
+    }
+
+    /// Returns the URL the CSV report is fetched from.
+    pub fn report_url(&self) -> &str {
+        self.report_url.as_str()
+    }
+}
+
+impl CcadbClientBuilder {
+    /// Build a blocking client, returning an error if the base URL or trust roots are invalid.
+    pub fn build_blocking(self) -> Result<CcadbClient, Error> {
+        let config = self.config()?;
+        // Unlike the async client, the blocking client defaults to a timeout: override it so that
+        // both behave the same.
+        let mut http = reqwest::blocking::Client::builder()
+            .user_agent(config.user_agent)
+            .tls_certs_only(config.trust_roots)
+            .timeout(config.timeout);
+        if let Some(proxy) = config.proxy {
+            http = http.proxy(proxy);
+        }
+
+        Ok(CcadbClient {
+            http: http.build()?,
+            report_url: config.report_url,
+        })
+    }
+}
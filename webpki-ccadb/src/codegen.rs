@@ -0,0 +1,1240 @@
+//! Generation of the Rust source code for the `webpki-roots` and `webpki-root-certs` crates.
+//!
+//! A [`Generator`] selects and validates the roots from one [`Snapshot`] of the CCADB report, so
+//! the code for both crates generated from it always holds the same roots, in the same order. The
+//! roots are written out as array elements by an [`Emitter`]: [`TrustAnchorEmitter`] for
+//! `webpki-roots`, and [`CertificateDerEmitter`] for `webpki-root-certs`.
+//!
+//! The generation is deterministic: the same snapshot always produces the same code, so the
+//! checked-in code can be verified by re-running it (see [`GeneratedFile::is_fresh()`]). The
+//! SHA-256 hash of the snapshot is recorded in the header of each generated file.
+
+use std::ascii::escape_default;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Write};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use aws_lc_rs::digest;
+use chrono::{DateTime, SecondsFormat, Utc};
+use pki_types::pem::PemObject;
+use pki_types::CertificateDer;
+use webpki::anchor_from_trusted_cert;
+use x509_parser::der_parser::Oid;
+use x509_parser::oid_registry::{OID_KEY_TYPE_EC_PUBLIC_KEY, OID_PKCS1_RSAENCRYPTION};
+use x509_parser::prelude::AttributeTypeAndValue;
+use x509_parser::x509::X509Name;
+
+use crate::{CertificateMetadata, Error, NameConstraints, ParsedRoot, Snapshot, TrustPolicy};
+
+/// A crate whose code can be generated from CCADB metadata.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Crate {
+    /// The `webpki-roots` crate, with roots in the `TrustAnchor` form.
+    WebpkiRoots,
+    /// The `webpki-root-certs` crate, with roots as full `CertificateDer`s.
+    WebpkiRootCerts,
+}
+
+impl Crate {
+    /// Every crate whose code can be generated.
+    pub const ALL: &'static [Self] = &[Self::WebpkiRoots, Self::WebpkiRootCerts];
+
+    /// Returns the crate with the given package name (if it is known).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|krate| krate.name() == name)
+    }
+
+    /// Returns the package name of the crate.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::WebpkiRoots => "webpki-roots",
+            Self::WebpkiRootCerts => "webpki-root-certs",
+        }
+    }
+
+    /// Generate every generated source file of the crate from `snapshot`.
+    ///
+    /// To generate more than one crate from the same snapshot, use a [`Generator`].
+    pub fn generate(&self, snapshot: &Snapshot) -> Result<Vec<GeneratedFile>, Error> {
+        Ok(Generator::new(snapshot)?.generate(*self))
+    }
+
+    /// Write the `extra_roots.rs` of the crate to `OUT_DIR`, holding the certificates of the PEM
+    /// file named by the [`EXTRA_ROOTS_PEM_VAR`] environment variable (if any).
+    ///
+    /// This is called by the build script of the crate, and prints the `cargo:` directives to
+    /// rebuild it when the variable or the file changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics (failing the build) if the variable is not an absolute path, or the file can not be
+    /// read or holds an invalid certificate.
+    pub fn build_extra_roots(&self) {
+        println!("cargo:rerun-if-env-changed={EXTRA_ROOTS_PEM_VAR}");
+
+        let mut certs = Vec::new();
+        if let Some(path) = env::var_os(EXTRA_ROOTS_PEM_VAR) {
+            let path = PathBuf::from(path);
+            // Build scripts run in the directory of the crate being built, which for a dependency
+            // is somewhere in the cargo registry, so a relative path would not mean what it seems.
+            if path.is_relative() {
+                panic!(
+                    "{EXTRA_ROOTS_PEM_VAR}: {}: must be an absolute path",
+                    path.display()
+                );
+            }
+            println!("cargo:rerun-if-changed={}", path.display());
+
+            certs = CertificateDer::pem_file_iter(&path)
+                .and_then(|iter| iter.collect::<Result<Vec<_>, _>>())
+                .unwrap_or_else(|err| panic!("{EXTRA_ROOTS_PEM_VAR}: {}: {err}", path.display()));
+        }
+
+        let code = self
+            .extra_roots(&certs)
+            .unwrap_or_else(|err| panic!("{EXTRA_ROOTS_PEM_VAR}: {err}"));
+        let out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set for build scripts"))
+            .join("extra_roots.rs");
+        fs::write(&out, code).unwrap_or_else(|err| panic!("{}: {err}", out.display()));
+    }
+
+    /// Generate the `EXTRA_ROOTS` array of the crate holding `certs`, as the expression that the
+    /// `EXTRA_ROOTS` const of the crate includes.
+    ///
+    /// Returns an error if any of `certs` can not be parsed as a trust anchor.
+    pub fn extra_roots(&self, certs: &[CertificateDer<'_>]) -> Result<String, Error> {
+        let mut code = String::from("&[\n");
+        for cert in certs {
+            let anchor = anchor_from_trusted_cert(cert).map_err(|_| {
+                let fingerprint = digest::digest(&digest::SHA256, cert);
+                Error::InvalidCertificate(hex::encode_upper(fingerprint))
+            })?;
+            match self {
+                Self::WebpkiRoots => trust_anchor(
+                    &anchor.subject,
+                    &anchor.subject_public_key_info,
+                    anchor.name_constraints.as_deref(),
+                    &mut code,
+                ),
+                Self::WebpkiRootCerts => writeln!(
+                    code,
+                    "   CertificateDer::from_slice(b\"{}\"),",
+                    escape(cert)
+                )
+                .unwrap(),
+            }
+        }
+        code.push_str("]\n");
+        Ok(code)
+    }
+}
+
+/// The environment variable naming a PEM file of extra roots to build into each crate, as read
+/// by [`Crate::build_extra_roots()`].
+pub const EXTRA_ROOTS_PEM_VAR: &str = "WEBPKI_ROOTS_EXTRA_PEM";
+
+impl Display for Crate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A generated source file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GeneratedFile {
+    /// The path of the file, relative to the root directory of its crate.
+    pub path: &'static str,
+    /// The generated contents of the file.
+    pub contents: String,
+}
+
+impl GeneratedFile {
+    /// Returns true if the file in the crate at `crate_dir` matches the generated contents.
+    ///
+    /// A missing file is not fresh.
+    pub fn is_fresh(&self, crate_dir: &Path) -> io::Result<bool> {
+        match fs::read_to_string(crate_dir.join(self.path)) {
+            Ok(old) => Ok(old == self.contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Write the generated contents to the file in the crate at `crate_dir`.
+    pub fn write(&self, crate_dir: &Path) -> io::Result<()> {
+        fs::write(crate_dir.join(self.path), &self.contents)
+    }
+}
+
+/// Generates the code of each [`Crate`] from one [`Snapshot`] of the CCADB report.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    snapshot_sha256: String,
+    snapshot_fetched_at: DateTime<Utc>,
+    snapshot_name_constraints: BTreeMap<String, Vec<u8>>,
+    tls_roots: Vec<Root>,
+    email_roots: Vec<Root>,
+}
+
+impl Generator {
+    /// Select the roots trusted for TLS and for email protection from `snapshot`, using the
+    /// default [`TrustPolicy`] and [`TrustPolicy::email_protection()`] as of the date the snapshot
+    /// was fetched, so the same snapshot always selects the same roots.
+    ///
+    /// The TLS roots with extra name constraints in the snapshot (see
+    /// [`Snapshot::with_name_constraints()`]) are limited to the names permitted by them, as well
+    /// as any they already have (see [`NameConstraints::intersect()`]).
+    ///
+    /// Returns an error if the snapshot can not be parsed, no root is trusted for email protection
+    /// (as in any complete report), the metadata of any selected root is invalid or does not match
+    /// its certificate, no root trusted for TLS has the fingerprint of some extra name
+    /// constraints, or the combined name constraints of a root would permit no names of some kind.
+    pub fn new(snapshot: &Snapshot) -> Result<Self, Error> {
+        let metadata = snapshot.metadata()?;
+        let at = snapshot.fetched_at().date_naive();
+        let tls_roots = TrustPolicy::default().at(at).apply(metadata.clone())?;
+        let email_roots = TrustPolicy::email_protection().at(at).apply(metadata)?;
+        if email_roots.is_empty() {
+            return Err(Error::NoRoots("email protection"));
+        }
+        let mut generator = Self {
+            snapshot_sha256: snapshot.sha256_hex(),
+            snapshot_fetched_at: snapshot.fetched_at(),
+            snapshot_name_constraints: snapshot.name_constraints().clone(),
+            tls_roots: tls_roots
+                .into_values()
+                .map(Root::new)
+                .collect::<Result<_, _>>()?,
+            email_roots: email_roots
+                .into_values()
+                .map(Root::new)
+                .collect::<Result<_, _>>()?,
+        };
+
+        for (sha256_fingerprint, der) in snapshot.name_constraints() {
+            let fingerprint = hex::decode(sha256_fingerprint)
+                .map_err(|_| Error::InvalidFingerprint(sha256_fingerprint.clone()))?;
+            let root = generator
+                .tls_roots
+                .iter_mut()
+                .find(|root| root.parsed.sha256_fingerprint[..] == fingerprint[..])
+                .ok_or_else(|| Error::UnknownFingerprint(sha256_fingerprint.clone()))?;
+
+            let name_constraints = NameConstraints::from_der(der)?;
+            let combined = match &root.parsed.name_constraints {
+                Some(existing) => existing.intersect(&name_constraints)?,
+                None => name_constraints,
+            };
+            root.parsed.name_constraints = Some(combined);
+        }
+        Ok(generator)
+    }
+
+    /// Generate every generated source file of `krate`.
+    pub fn generate(&self, krate: Crate) -> Vec<GeneratedFile> {
+        match krate {
+            Crate::WebpkiRoots => self.webpki_roots(),
+            Crate::WebpkiRootCerts => self.webpki_root_certs(),
+        }
+    }
+
+    /// Returns the roots trusted for TLS, ordered by SHA-256 fingerprint.
+    pub fn tls_roots(&self) -> &[Root] {
+        &self.tls_roots
+    }
+
+    /// Returns the roots trusted for email protection, ordered by SHA-256 fingerprint.
+    pub fn email_roots(&self) -> &[Root] {
+        &self.email_roots
+    }
+
+    fn webpki_roots(&self) -> Vec<GeneratedFile> {
+        let mut code = String::with_capacity(256 * 1_024);
+        code.push_str(WEBPKI_ROOTS_DOCS);
+        code.push_str(&self.notice("library"));
+        code.push('\n');
+        code.push_str(CRATE_ATTRIBUTES);
+        code.push('\n');
+        code.push_str(WEBPKI_ROOTS_ITEMS);
+        code.push_str(&root_array(
+            "TLS_SERVER_ROOTS",
+            &self.tls_roots,
+            &TrustAnchorEmitter,
+        ));
+        for (name, algorithm, oid) in [
+            (
+                "TLS_SERVER_ROOTS_ECDSA",
+                "ECDSA",
+                OID_KEY_TYPE_EC_PUBLIC_KEY,
+            ),
+            ("TLS_SERVER_ROOTS_RSA", "RSA", OID_PKCS1_RSAENCRYPTION),
+        ] {
+            let subset = self
+                .tls_roots
+                .iter()
+                .filter(|root| root.public_key_algorithm == oid)
+                .cloned()
+                .collect::<Vec<_>>();
+            writeln!(
+                code,
+                "\n/// The subset of [`TLS_SERVER_ROOTS`] with {algorithm} public keys."
+            )
+            .unwrap();
+            code.push_str(&root_array(name, &subset, &SubsetEmitter));
+        }
+        code.push_str(&distrust_after(&self.tls_roots));
+        code.push_str(ROOTS_INFO_DOCS);
+        code.push_str("pub const TLS_SERVER_ROOTS_INFO: &[RootInfo] = &[\n");
+        code.push_str(&root_infos(&self.tls_roots));
+        code.push_str("];\n");
+        code.push_str(&lookup_indices("TLS_SERVER_ROOTS", &self.tls_roots));
+        let lib = GeneratedFile {
+            path: "src/lib.rs",
+            contents: code,
+        };
+
+        let mut code = String::with_capacity(256 * 1_024);
+        code.push_str(EMAIL_DOCS);
+        code.push_str(&self.notice("file"));
+        code.push('\n');
+        code.push_str(EMAIL_ITEMS);
+        code.push_str(&root_array(
+            "EMAIL_PROTECTION_ROOTS",
+            &self.email_roots,
+            &TrustAnchorEmitter,
+        ));
+        let email = GeneratedFile {
+            path: "src/email.rs",
+            contents: code,
+        };
+
+        vec![lib, email, search()]
+    }
+
+    /// Returns the comment noting that the generated `what` (e.g. "library") must not be edited,
+    /// and which snapshot it was generated from: its hash, the time it was fetched, as of
+    /// which the roots were selected, and any extra name constraints it records.
+    fn notice(&self, what: &str) -> String {
+        let mut notice = format!(
+            r#"//
+// This {what} is automatically generated from the Mozilla
+// IncludedCACertificateReportPEMCSV report via ccadb.org. Don't edit it.
+//
+// The generation is done deterministically so you can verify it
+// yourself by inspecting and re-running the generation process.
+//
+// Generated from the CCADB snapshot fetched at {}, with SHA-256:
+// {}
+"#,
+            self.snapshot_fetched_at
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+            self.snapshot_sha256
+        );
+        if !self.snapshot_name_constraints.is_empty() {
+            notice.push_str(
+                "//\n// With extra name constraints (in DER) for the roots with SHA-256:\n",
+            );
+        }
+        for (fingerprint, der) in &self.snapshot_name_constraints {
+            writeln!(notice, "// {fingerprint}:\n//   {}", hex::encode(der)).unwrap();
+        }
+        notice
+    }
+
+    fn webpki_root_certs(&self) -> Vec<GeneratedFile> {
+        let mut code = String::with_capacity(256 * 1_024);
+        code.push_str(WEBPKI_ROOT_CERTS_DOCS);
+        code.push_str(&self.notice("library"));
+        code.push('\n');
+        code.push_str(CRATE_ATTRIBUTES);
+        code.push('\n');
+        code.push_str(WEBPKI_ROOT_CERTS_ITEMS);
+        code.push_str(&root_array(
+            "TLS_SERVER_ROOT_CERTS",
+            &self.tls_roots,
+            &CertificateDerEmitter,
+        ));
+        code.push_str(&validity("TLS_SERVER_ROOT_CERTS", &self.tls_roots));
+        code.push_str(&lookup_indices("TLS_SERVER_ROOT_CERTS", &self.tls_roots));
+
+        vec![
+            GeneratedFile {
+                path: "src/lib.rs",
+                contents: code,
+            },
+            search(),
+        ]
+    }
+}
+
+/// Returns the module searching the index tables generated by `lookup_indices()`, which is the
+/// same in each crate.
+fn search() -> GeneratedFile {
+    GeneratedFile {
+        path: "src/search.rs",
+        contents: SEARCH_MODULE.to_owned(),
+    }
+}
+
+/// A root selected for generation, with the values derived from its certificate.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Root {
+    /// The CCADB metadata of the root.
+    pub metadata: CertificateMetadata,
+
+    /// The parsed CCADB metadata of the root, whose DER matches its SHA-256 fingerprint.
+    pub parsed: ParsedRoot,
+
+    /// The subject of the certificate, in the webpki trust anchor form (without the outer
+    /// SEQUENCE).
+    pub subject: Vec<u8>,
+
+    /// The subject public key info of the certificate, in the webpki trust anchor form
+    /// (without the outer SEQUENCE).
+    pub subject_public_key_info: Vec<u8>,
+
+    /// The issuer of the certificate, formatted by [`name_to_string()`].
+    pub issuer_name: String,
+
+    /// The subject of the certificate, formatted by [`name_to_string()`].
+    pub subject_name: String,
+
+    /// The algorithm OID of the subject public key of the certificate.
+    pub public_key_algorithm: Oid<'static>,
+
+    /// The `notBefore` time of the certificate, in seconds since the Unix epoch.
+    pub not_before: i64,
+
+    /// The `notAfter` time of the certificate, in seconds since the Unix epoch.
+    pub not_after: i64,
+}
+
+impl Root {
+    /// Parse `metadata` and its certificate, checking that the certificate matches the
+    /// metadata fingerprint.
+    pub fn new(metadata: CertificateMetadata) -> Result<Self, Error> {
+        let parsed = metadata.parse()?;
+        let calculated_fp = digest::digest(&digest::SHA256, &parsed.der);
+        if calculated_fp.as_ref() != parsed.sha256_fingerprint {
+            return Err(Error::FingerprintMismatch(metadata.sha256_fingerprint));
+        }
+
+        let invalid = || Error::InvalidCertificate(metadata.sha256_fingerprint.clone());
+        let ta = anchor_from_trusted_cert(&parsed.der).map_err(|_| invalid())?;
+        let (_, cert) = x509_parser::parse_x509_certificate(&parsed.der).map_err(|_| invalid())?;
+        Ok(Self {
+            subject: ta.subject.as_ref().to_vec(),
+            subject_public_key_info: ta.subject_public_key_info.as_ref().to_vec(),
+            issuer_name: name_to_string(cert.issuer()),
+            subject_name: name_to_string(cert.subject()),
+            public_key_algorithm: cert.public_key().algorithm.algorithm.to_owned(),
+            not_before: cert.validity().not_before.timestamp(),
+            not_after: cert.validity().not_after.timestamp(),
+            parsed,
+            metadata,
+        })
+    }
+
+    /// Returns the "Distrust for TLS After Date" of the root (if any), as the number of
+    /// seconds since the Unix epoch at the end of that date.
+    pub fn tls_distrust_after_secs(&self) -> Option<i64> {
+        // Certificates issued at any time on the distrust after date are still trusted.
+        let date = self.parsed.tls_distrust_after?;
+        Some(date.and_hms_opt(23, 59, 59).unwrap().and_utc().timestamp())
+    }
+
+    /// Append the root to `code` as a `pki_types::TrustAnchor` array element.
+    fn trust_anchor(&self, code: &mut String) {
+        let name_constraints = self.parsed.name_constraints.as_ref().map(|nc| nc.to_der());
+        trust_anchor(
+            &self.subject,
+            &self.subject_public_key_info,
+            name_constraints.as_deref(),
+            code,
+        );
+    }
+}
+
+/// Writes roots out as the elements of a generated array.
+pub trait Emitter {
+    /// Returns the type of the array elements, e.g. `TrustAnchor<'static>`.
+    fn element_type(&self) -> &str;
+
+    /// Append the array element (with any comments) for `root` to `code`.
+    fn emit(&self, root: &Root, code: &mut String);
+}
+
+/// Emits roots as commented `pki_types::TrustAnchor`s, as in `webpki-roots`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrustAnchorEmitter;
+
+impl Emitter for TrustAnchorEmitter {
+    fn element_type(&self) -> &str {
+        "TrustAnchor<'static>"
+    }
+
+    fn emit(&self, root: &Root, code: &mut String) {
+        // Write comment
+        code.push_str("  /*\n");
+        writeln!(code, "   * Issuer: {}", root.issuer_name).unwrap();
+        writeln!(code, "   * Subject: {}", root.subject_name).unwrap();
+        writeln!(code, "   * Label: {:?}", root.parsed.label).unwrap();
+        writeln!(code, "   * Serial: {}", root.parsed.serial).unwrap();
+        writeln!(
+            code,
+            "   * SHA256 Fingerprint: {}",
+            x509_parser::utils::format_serial(&root.parsed.sha256_fingerprint)
+        )
+        .unwrap();
+        for ln in root.metadata.pem().lines() {
+            code.push_str("   * ");
+            code.push_str(ln.trim());
+            code.push('\n');
+        }
+        code.push_str("   */\n");
+
+        // Write the code
+        root.trust_anchor(code);
+        code.push('\n');
+    }
+}
+
+/// Emits roots as `pki_types::TrustAnchor`s labelled with a single comment, for the subsets of
+/// `TLS_SERVER_ROOTS` whose full comments are already in that array.
+#[derive(Debug, Clone, Copy, Default)]
+struct SubsetEmitter;
+
+impl Emitter for SubsetEmitter {
+    fn element_type(&self) -> &str {
+        "TrustAnchor<'static>"
+    }
+
+    fn emit(&self, root: &Root, code: &mut String) {
+        writeln!(code, "  // {:?}", root.parsed.label).unwrap();
+        root.trust_anchor(code);
+    }
+}
+
+/// Append a `pki_types::TrustAnchor` array element with the given (DER encoded) parts to `code`.
+fn trust_anchor(
+    subject: &[u8],
+    subject_public_key_info: &[u8],
+    name_constraints: Option<&[u8]>,
+    code: &mut String,
+) {
+    code.push_str("  TrustAnchor {\n");
+    writeln!(
+        code,
+        "    subject: Der::from_slice(b\"{}\"),",
+        escape(subject)
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "    subject_public_key_info: Der::from_slice(b\"{}\"),",
+        escape(subject_public_key_info)
+    )
+    .unwrap();
+    match name_constraints {
+        Some(nc) => writeln!(
+            code,
+            "    name_constraints: Some(Der::from_slice(b\"{}\"))",
+            escape(nc)
+        )
+        .unwrap(),
+        None => code.push_str("    name_constraints: None\n"),
+    }
+    code.push_str("  },\n");
+}
+
+/// Emits roots as labelled `pki_types::CertificateDer`s, as in `webpki-root-certs`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CertificateDerEmitter;
+
+impl Emitter for CertificateDerEmitter {
+    fn element_type(&self) -> &str {
+        "CertificateDer<'static>"
+    }
+
+    fn emit(&self, root: &Root, code: &mut String) {
+        writeln!(code, "   // {:?}", root.parsed.label).unwrap();
+        writeln!(
+            code,
+            "   CertificateDer::from_slice(b\"{}\"),",
+            escape(&root.parsed.der)
+        )
+        .unwrap();
+    }
+}
+
+/// Generate a public array constant called `name`, holding `roots` as written by `emitter`.
+pub fn root_array(name: &str, roots: &[Root], emitter: &dyn Emitter) -> String {
+    let mut code = String::with_capacity(256 * 1_024);
+    write!(code, "pub const {name}: &[{}] = &[", emitter.element_type()).unwrap();
+    // Keep an empty array formatted the way rustfmt expects.
+    if !roots.is_empty() {
+        code.push('\n');
+        for root in roots {
+            emitter.emit(root, &mut code);
+        }
+    }
+    code.push_str("];\n");
+    code
+}
+
+/// Escape `bytes` for use in a byte string literal.
+///
+/// Every generated byte string is escaped this way, so the same bytes always look the same.
+pub fn escape(bytes: &[u8]) -> String {
+    let mut escaped = String::with_capacity(bytes.len() * 4);
+    for &b in bytes {
+        write!(&mut escaped, "{}", escape_default(b)).unwrap();
+    }
+    escaped
+}
+
+/// The built-in x509_parser::X509Name Display impl uses a different sort order than
+/// the one historically used by mkcert.org^[0]. We re-create that sort order here to
+/// avoid unnecessary churn in the generated code.
+///
+/// [0]: <https://github.com/Lukasa/mkcert/blob/6911a8f68681f4d6a795c1f6db7b063f75b03b5a/certs/convert_mozilla_certdata.go#L405-L428>
+pub fn name_to_string(name: &X509Name<'_>) -> String {
+    let mut ret = String::with_capacity(256);
+
+    if let Some(cn) = name
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+    {
+        write!(ret, "CN={cn}").unwrap();
+    }
+
+    let mut append_attrs = |attrs: Vec<&AttributeTypeAndValue<'_>>, label| {
+        let str_parts = attrs
+            .iter()
+            .filter_map(|attr| attr.as_str().ok())
+            .collect::<Vec<_>>()
+            .join("/");
+        if !str_parts.is_empty() {
+            if !ret.is_empty() {
+                ret.push(' ');
+            }
+            write!(ret, "{label}={str_parts}").unwrap();
+        }
+    };
+
+    append_attrs(name.iter_organization().collect(), "O");
+    append_attrs(name.iter_organizational_unit().collect(), "OU");
+
+    ret
+}
+
+/// Generate the `TLS_SERVER_ROOT_DISTRUST_AFTER` table for the roots in `TLS_SERVER_ROOTS`.
+fn distrust_after(roots: &[Root]) -> String {
+    let mut entries = String::new();
+    for (index, root) in roots.iter().enumerate() {
+        let (Some(date), Some(secs)) = (
+            root.parsed.tls_distrust_after,
+            root.tls_distrust_after_secs(),
+        ) else {
+            continue;
+        };
+
+        let label = &root.parsed.label;
+        writeln!(
+            &mut entries,
+            "    // {label:?}, distrusted for TLS after {date}."
+        )
+        .unwrap();
+        writeln!(
+            &mut entries,
+            "    ({index}, UnixTime::since_unix_epoch(Duration::from_secs({secs}))),"
+        )
+        .unwrap();
+    }
+
+    let mut code = String::from(DISTRUST_AFTER_DOCS);
+    code.push_str("pub const TLS_SERVER_ROOT_DISTRUST_AFTER: &[(usize, UnixTime)] = &[");
+    // Keep an empty table formatted the way rustfmt expects.
+    if !entries.is_empty() {
+        code.push('\n');
+        code.push_str(&entries);
+    }
+    code.push_str("];\n");
+    code
+}
+
+/// Generate the `VALIDITY` table of the `notBefore` and `notAfter` times of each root in the
+/// `array` of `roots`.
+fn validity(array: &str, roots: &[Root]) -> String {
+    let mut code = String::new();
+    writeln!(code).unwrap();
+    writeln!(
+        code,
+        "/// The `notBefore` and `notAfter` times of each certificate in [`{array}`],"
+    )
+    .unwrap();
+    code.push_str(
+        "/// at the same index, in seconds since the Unix epoch.
+",
+    );
+    code.push_str("const VALIDITY: &[(u64, u64)] = &[");
+    // Keep an empty table formatted the way rustfmt expects.
+    if !roots.is_empty() {
+        code.push('\n');
+        for root in roots {
+            writeln!(code, "    ({}, {}),", root.not_before, root.not_after).unwrap();
+        }
+    }
+    code.push_str("];\n");
+    code
+}
+
+/// Generate the sorted index tables into the `array` of `roots` used by the `find_by_*`
+/// lookup functions.
+///
+/// Each table holds the key of each root with its index in `array`, so the lookup functions
+/// can search them in a `const fn`.
+fn lookup_indices(array: &str, roots: &[Root]) -> String {
+    let mut fingerprints = roots
+        .iter()
+        .enumerate()
+        .map(|(index, root)| (root.parsed.sha256_fingerprint, index))
+        .collect::<Vec<_>>();
+    fingerprints.sort();
+
+    let mut code = String::new();
+    writeln!(code).unwrap();
+    writeln!(
+        code,
+        "/// Indices into [`{array}`] sorted by SHA-256 fingerprint, with the fingerprints."
+    )
+    .unwrap();
+    write!(code, "const SHA256_INDEX: &[([u8; 32], usize)] = &[").unwrap();
+    code.push_str(&index_entries(fingerprints.iter().map(
+        |(fingerprint, index)| format!("(*b\"{}\", {index})", escape(fingerprint)),
+    )));
+    code.push_str("];\n");
+
+    for (name, key, key_of) in [
+        ("SUBJECT_INDEX", "subject", subject_of as fn(&Root) -> &[u8]),
+        ("SPKI_INDEX", "subject public key info", spki_of),
+    ] {
+        let mut entries = roots
+            .iter()
+            .enumerate()
+            .map(|(index, root)| (key_of(root), index))
+            .collect::<Vec<_>>();
+        entries.sort();
+        writeln!(code).unwrap();
+        writeln!(
+            code,
+            "/// Indices into [`{array}`] sorted by {key}, with the {key}."
+        )
+        .unwrap();
+        write!(code, "const {name}: &[(&[u8], usize)] = &[").unwrap();
+        code.push_str(&index_entries(
+            entries
+                .iter()
+                .map(|(key, index)| format!("(b\"{}\", {index})", escape(key))),
+        ));
+        code.push_str("];\n");
+    }
+
+    code
+}
+
+fn subject_of(root: &Root) -> &[u8] {
+    &root.subject
+}
+
+fn spki_of(root: &Root) -> &[u8] {
+    &root.subject_public_key_info
+}
+
+/// Format `entries` as the elements of an index table literal, one per line.
+fn index_entries(entries: impl Iterator<Item = String>) -> String {
+    let mut code = String::new();
+    for entry in entries {
+        if code.is_empty() {
+            code.push('\n');
+        }
+        writeln!(code, "    {entry},").unwrap();
+    }
+    code
+}
+
+/// Generate the `RootInfo` array elements for `roots`.
+fn root_infos(roots: &[Root]) -> String {
+    let mut code = String::with_capacity(128 * 1_024);
+    for root in roots {
+        code.push_str("  RootInfo {\n");
+        writeln!(code, "    label: {:?},", root.parsed.label).unwrap();
+        writeln!(
+            code,
+            "    sha256_fingerprint: *b\"{}\",",
+            escape(&root.parsed.sha256_fingerprint)
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "    serial: b\"{}\",",
+            escape(&root.parsed.serial.to_bytes_be())
+        )
+        .unwrap();
+        writeln!(code, "    issuer: {:?},", root.issuer_name).unwrap();
+        writeln!(code, "    subject: {:?},", root.subject_name).unwrap();
+        match root.tls_distrust_after_secs() {
+            Some(secs) => writeln!(
+                code,
+                "    tls_distrust_after: Some(UnixTime::since_unix_epoch(Duration::from_secs({secs}))),"
+            )
+            .unwrap(),
+            None => code.push_str("    tls_distrust_after: None,\n"),
+        }
+        writeln!(
+            code,
+            "    not_before: UnixTime::since_unix_epoch(Duration::from_secs({})),",
+            root.not_before
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "    not_after: UnixTime::since_unix_epoch(Duration::from_secs({})),",
+            root.not_after
+        )
+        .unwrap();
+        code.push_str("  },\n");
+    }
+
+    code
+}
+
+const CRATE_ATTRIBUTES: &str = r#"#![no_std]
+#![forbid(unsafe_code, unstable_features)]
+#![deny(
+    elided_lifetimes_in_paths,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_extern_crates,
+    unused_qualifications
+)]
+"#;
+
+const WEBPKI_ROOTS_DOCS: &str = r#"//! A compiled-in copy of the root certificates trusted by Mozilla.
+//!
+//! To use this library with rustls 0.22:
+//!
+//! ```rust
+//! let root_store = rustls::RootCertStore {
+//!   roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+//! };
+//! ```
+//!
+//! Or with the `rustls` crate feature enabled, use `webpki_roots::root_cert_store()`, or
+//! `webpki_roots::root_cert_store_with()` to add extra roots of your own.
+//!
+//! This library is suitable for use in applications that can always be recompiled and instantly deployed.
+//! For applications that are deployed to end-users and cannot be recompiled, or which need certification
+//! before deployment, consider a library that uses the platform native certificate verifier such as
+//! [rustls-platform-verifier]. This has the additional benefit of supporting OS provided CA constraints
+//! and revocation data.
+//!
+//! [rustls-platform-verifier]: https://docs.rs/rustls-platform-verifier
+"#;
+
+const WEBPKI_ROOTS_ITEMS: &str = r#"#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[allow(unused_imports)] // `Duration` is unused if no roots have a distrust after date.
+use core::time::Duration;
+
+use pki_types::{Der, TrustAnchor, UnixTime};
+
+mod email;
+pub use email::EMAIL_PROTECTION_ROOTS;
+
+mod info;
+pub use info::RootInfo;
+
+mod lookup;
+mod search;
+pub use lookup::{find_by_sha256, find_by_spki, find_by_subject};
+
+mod validity;
+pub use validity::roots_valid_at;
+
+#[cfg(feature = "rustls")]
+mod der;
+
+#[cfg(feature = "alloc")]
+mod root_set;
+#[cfg(feature = "alloc")]
+pub use root_set::RootSet;
+
+#[cfg(feature = "rustls")]
+mod store;
+#[cfg(feature = "rustls")]
+pub use store::{root_cert_store, root_cert_store_with, InvalidExtraRoot};
+
+#[cfg(feature = "rustls")]
+mod verifier;
+#[cfg(feature = "rustls")]
+pub use verifier::DistrustAfterVerifier;
+
+/// Extra root certificates added at build time.
+///
+/// With the `extra-roots` crate feature, if the `WEBPKI_ROOTS_EXTRA_PEM` environment variable
+/// holds the absolute path of a PEM file when this crate is built, this holds the trust anchors
+/// of every certificate in it. Otherwise it is empty. These are not included in
+/// [`TLS_SERVER_ROOTS`], or any of the tables derived from it, but are included in the
+/// `RootCertStore`s built with the `rustls` crate feature.
+pub const EXTRA_ROOTS: &[TrustAnchor<'static>] =
+    include!(concat!(env!("OUT_DIR"), "/extra_roots.rs"));
+
+"#;
+
+const ROOTS_INFO_DOCS: &str = r#"
+/// Metadata about each root in [`TLS_SERVER_ROOTS`], at the same index.
+"#;
+
+const DISTRUST_AFTER_DOCS: &str = r#"
+/// The times after which roots in [`TLS_SERVER_ROOTS`] are distrusted for TLS.
+///
+/// Each entry holds the index of a root in [`TLS_SERVER_ROOTS`], and the end (UTC) of its
+/// "Distrust for TLS After Date" in the CCADB. Certificates issued (i.e. with a `notBefore`)
+/// after that time should not be trusted when they chain to that root, while those issued
+/// before remain trusted. Entries are sorted by index.
+///
+/// rustls users can enforce this with `DistrustAfterVerifier` (requires the `rustls` crate
+/// feature).
+"#;
+
+const EMAIL_DOCS: &str = r#"//! Root certificates trusted by Mozilla for email protection (S/MIME).
+"#;
+
+const EMAIL_ITEMS: &str = r#"use pki_types::{Der, TrustAnchor};
+
+/// The roots trusted by Mozilla for email protection (S/MIME): those with the `Email` trust bit,
+/// excluding those past their distrust for S/MIME after date by more than the grace period.
+"#;
+
+const WEBPKI_ROOT_CERTS_DOCS: &str = r#"//! A compiled-in copy of the full X.509 root certificates trusted by Mozilla.
+//!
+//! You should generally prefer to use [`webpki-roots`] when using [`rustls`] or [`webpki`] as it is
+//! more space efficient and convenient for that use.
+//!
+//! This library is suitable for use in applications that can always be recompiled and instantly deployed.
+//! For applications that are deployed to end-users and cannot be recompiled, or which need certification
+//! before deployment, consider a library that uses the platform native certificate verifier such as
+//! [`rustls-platform-verifier`]. This has the additional benefit of supporting OS provided CA constraints
+//! and revocation data.
+//!
+//! [`webpki-roots`]: https://docs.rs/webpki-roots
+//! [`webpki`]: https://docs.rs/rustls-webpki
+//! [`rustls`]: https://docs.rs/rustls
+//! [`rustls-platform-verifier`]: https://docs.rs/rustls-platform-verifier
+"#;
+
+const WEBPKI_ROOT_CERTS_ITEMS: &str = r#"use pki_types::CertificateDer;
+
+mod lookup;
+mod search;
+pub use lookup::{find_by_sha256, find_by_spki, find_by_subject};
+
+mod validity;
+pub use validity::roots_valid_at;
+
+/// Extra root certificates added at build time.
+///
+/// With the `extra-roots` crate feature, if the `WEBPKI_ROOTS_EXTRA_PEM` environment variable
+/// holds the absolute path of a PEM file when this crate is built, this holds every certificate
+/// in it. Otherwise it is empty. These are not included in [`TLS_SERVER_ROOT_CERTS`], or the
+/// lookup tables derived from it.
+pub const EXTRA_ROOTS: &[CertificateDer<'static>] =
+    include!(concat!(env!("OUT_DIR"), "/extra_roots.rs"));
+
+"#;
+
+const SEARCH_MODULE: &str = r#"//! Binary search of the generated index tables, in a `const fn`.
+//
+// This module is generated identically in each crate by webpki-ccadb. Don't edit it.
+
+use core::cmp::Ordering;
+
+/// Binary search `index` (sorted by key) for the first entry with `key`, and return its index.
+pub(crate) const fn search(index: &[(&[u8], usize)], key: &[u8]) -> Option<usize> {
+    let (mut low, mut high) = (0, index.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match compare(index[mid].0, key) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Equal | Ordering::Greater => high = mid,
+        }
+    }
+
+    match low < index.len() {
+        true => match compare(index[low].0, key) {
+            Ordering::Equal => Some(index[low].1),
+            _ => None,
+        },
+        false => None,
+    }
+}
+
+/// Binary search `index` (sorted by fingerprint) for the entry with `fingerprint`, and return
+/// its index.
+pub(crate) const fn search_sha256(
+    index: &[([u8; 32], usize)],
+    fingerprint: &[u8; 32],
+) -> Option<usize> {
+    let (mut low, mut high) = (0, index.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match compare(&index[mid].0, fingerprint) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Some(index[mid].1),
+        }
+    }
+    None
+}
+
+/// Lexicographically compare `a` and `b`.
+const fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] < b[i] {
+            return Ordering::Less;
+        } else if a[i] > b[i] {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// If `der` is a single DER encoded SEQUENCE, returns its contents. Otherwise returns `der`.
+///
+/// The trust anchor representation elides the outer SEQUENCE of the subject and subject public
+/// key info, which is never a single SEQUENCE itself: a `Name`'s contents are SETs, and a
+/// `SubjectPublicKeyInfo`'s contents are an `AlgorithmIdentifier` followed by a BIT STRING.
+pub(crate) const fn strip_sequence(der: &[u8]) -> &[u8] {
+    let (header_len, len) = match der {
+        [0x30, len @ 0..=0x7f, ..] => (2, *len as usize),
+        [0x30, 0x81, a, ..] => (3, *a as usize),
+        [0x30, 0x82, a, b, ..] => (4, (*a as usize) << 8 | *b as usize),
+        [0x30, 0x83, a, b, c, ..] => (5, (*a as usize) << 16 | (*b as usize) << 8 | *c as usize),
+        _ => return der,
+    };
+    if header_len + len != der.len() {
+        return der;
+    }
+
+    let mut contents = der;
+    let mut skip = header_len;
+    while skip > 0 {
+        if let [_, rest @ ..] = contents {
+            contents = rest;
+        }
+        skip -= 1;
+    }
+    contents
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::TEST_ROOT_CSV;
+
+    #[test]
+    fn test_crate_names() {
+        for &krate in Crate::ALL {
+            assert_eq!(Crate::from_name(krate.name()), Some(krate));
+        }
+        assert_eq!(Crate::from_name("webpki-ccadb"), None);
+    }
+
+    #[test]
+    fn test_generate() {
+        let generator = Generator::new(&test_snapshot(TEST_ROOT_CSV)).unwrap();
+        assert_eq!(generator.tls_roots().len(), 1);
+        assert_eq!(generator.email_roots().len(), 1);
+
+        let files = generator.generate(Crate::WebpkiRoots);
+        let paths = files.iter().map(|file| file.path).collect::<Vec<_>>();
+        assert_eq!(paths, ["src/lib.rs", "src/email.rs", "src/search.rs"]);
+        let lib = &files[0].contents;
+        assert!(lib.contains("   * Label: \"DigiCert Global Root CA\"\n"));
+        let hash = test_snapshot(TEST_ROOT_CSV).sha256_hex();
+        assert!(lib.contains(&format!(
+            "snapshot fetched at 2024-06-30T12:34:56Z, with SHA-256:\n// {hash}\n"
+        )));
+
+        assert!(files[1].contents.contains("TrustAnchor {"));
+        assert!(files[1]
+            .contents
+            .contains(&format!("SHA-256:\n// {hash}\n")));
+        let search = files[2].clone();
+        let files = generator.generate(Crate::WebpkiRootCerts);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1], search);
+        assert!(files[0]
+            .contents
+            .contains("   // \"DigiCert Global Root CA\"\n"));
+
+        // An empty set of email protection roots is never generated.
+        let csv = TEST_ROOT_CSV.replace("Websites;Email,", "Websites,");
+        let err = Generator::new(&test_snapshot(&csv)).unwrap_err();
+        assert!(matches!(err, Error::NoRoots("email protection")));
+    }
+
+    #[test]
+    fn test_snapshot_name_constraints() {
+        const FINGERPRINT: &str =
+            "4348A0E9444C78CB265E058D5E8944B4D84F9662BD26DB257F8934A443C70161";
+        let der = |constraints: &str| NameConstraints::from_str(constraints).unwrap().to_der();
+        let constrained = |constraints: &str| {
+            test_snapshot(TEST_ROOT_CSV)
+                .with_name_constraints(FINGERPRINT, der(constraints))
+                .unwrap()
+        };
+
+        let generator = Generator::new(&constrained("DNS:test.example")).unwrap();
+        assert_eq!(
+            generator.tls_roots()[0].parsed.name_constraints,
+            Some(NameConstraints::from_str("DNS:test.example").unwrap())
+        );
+        let lib = &generator.generate(Crate::WebpkiRoots)[0].contents;
+        assert!(lib.contains(&format!(
+            "name_constraints: Some(Der::from_slice(b\"{}\"))",
+            escape(&der("DNS:test.example"))
+        )));
+        // The extra name constraints are recorded with the snapshot they were generated from.
+        assert!(lib.contains(&format!(
+            "// {FINGERPRINT}:\n//   {}\n",
+            hex::encode(der("DNS:test.example"))
+        )));
+
+        // Combined with the constraints the CCADB applies to the root.
+        let csv = TEST_ROOT_CSV.replace("Websites;Email,,,,", "Websites;Email,,,*.example,");
+        let snapshot = test_snapshot(&csv)
+            .with_name_constraints(FINGERPRINT, der("DNS:test.example"))
+            .unwrap();
+        let generator = Generator::new(&snapshot).unwrap();
+        assert_eq!(
+            generator.tls_roots()[0].parsed.name_constraints,
+            Some(NameConstraints::from_str("DNS:test.example").unwrap())
+        );
+        let snapshot = test_snapshot(&csv)
+            .with_name_constraints(FINGERPRINT, der("DNS:.tr"))
+            .unwrap();
+        let err = Generator::new(&snapshot).unwrap_err();
+        assert!(matches!(err, Error::InvalidNameConstraints(_)));
+
+        let snapshot = test_snapshot(TEST_ROOT_CSV)
+            .with_name_constraints(&"00".repeat(32), der("DNS:.example"))
+            .unwrap();
+        let err = Generator::new(&snapshot).unwrap_err();
+        assert!(matches!(err, Error::UnknownFingerprint(_)));
+    }
+
+    #[test]
+    fn test_generate_at_snapshot_date() {
+        // Distrusted for TLS after 2020-01-01, so no longer trusted a grace period later.
+        let csv = TEST_ROOT_CSV.replace("Websites;Email,,", "Websites;Email,2020.01.01,");
+        let snapshot = |date: &str| {
+            let fetched_at = format!("{date}T00:00:00Z").parse().unwrap();
+            Snapshot::new("https://ccadb.example/", fetched_at, csv.clone())
+        };
+
+        let generator = Generator::new(&snapshot("2020-06-01")).unwrap();
+        assert_eq!(generator.tls_roots().len(), 1);
+        let generator = Generator::new(&snapshot("2024-06-01")).unwrap();
+        assert!(generator.tls_roots().is_empty());
+    }
+
+    #[test]
+    fn test_root_array() {
+        let metadata = crate::read_ccadb_csv(TEST_ROOT_CSV.as_bytes()).unwrap();
+        let roots = vec![Root::new(metadata[0].clone()).unwrap()];
+        assert_eq!(
+            root_array("EMPTY", &[], &CertificateDerEmitter),
+            "pub const EMPTY: &[CertificateDer<'static>] = &[];\n"
+        );
+
+        let code = root_array("ROOTS", &roots, &CertificateDerEmitter);
+        assert!(code.starts_with("pub const ROOTS: &[CertificateDer<'static>] = &[\n"));
+        assert!(code.contains(&escape(&roots[0].parsed.der)));
+        assert!(code.ends_with("),\n];\n"));
+    }
+
+    #[test]
+    fn test_extra_roots() {
+        let metadata = crate::read_ccadb_csv(TEST_ROOT_CSV.as_bytes()).unwrap();
+        let root = Root::new(metadata[0].clone()).unwrap();
+        let certs = [root.parsed.der.clone()];
+
+        let code = Crate::WebpkiRoots.extra_roots(&certs).unwrap();
+        assert!(code.starts_with("&[\n  TrustAnchor {\n"));
+        assert!(code.contains(&format!(
+            "    subject: Der::from_slice(b\"{}\"),\n",
+            escape(&root.subject)
+        )));
+
+        let code = Crate::WebpkiRootCerts.extra_roots(&certs).unwrap();
+        assert!(code.starts_with("&[\n   CertificateDer::from_slice(b\""));
+        assert!(code.contains(&escape(&root.parsed.der)));
+
+        let invalid = [CertificateDer::from(&b"\x30\x00"[..])];
+        let err = Crate::WebpkiRoots.extra_roots(&invalid).unwrap_err();
+        assert!(matches!(err, Error::InvalidCertificate(_)));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape(b"a\"\\\x00\xff"), "a\\\"\\\\\\x00\\xff");
+    }
+
+    #[test]
+    fn test_fingerprint_mismatch() {
+        let csv = TEST_ROOT_CSV.replace(
+            "4348A0E9444C78CB265E058D5E8944B4D84F9662BD26DB257F8934A443C70161",
+            &"00".repeat(32),
+        );
+        let err = Generator::new(&test_snapshot(&csv)).unwrap_err();
+        assert!(matches!(err, Error::FingerprintMismatch(_)));
+    }
+
+    #[test]
+    fn test_name_to_string() {
+        let metadata = crate::read_ccadb_csv(TEST_ROOT_CSV.as_bytes()).unwrap();
+        let root = Root::new(metadata[0].clone()).unwrap();
+        assert_eq!(
+            root.subject_name,
+            "CN=DigiCert Global Root CA O=DigiCert Inc OU=www.digicert.com"
+        );
+    }
+
+    fn test_snapshot(csv: &str) -> Snapshot {
+        Snapshot::new(
+            "https://ccadb.example/",
+            "2024-06-30T12:34:56Z".parse().unwrap(),
+            csv.to_string(),
+        )
+    }
+}
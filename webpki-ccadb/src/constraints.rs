@@ -0,0 +1,933 @@
+use std::fmt;
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use x509_parser::extensions::{GeneralName, NameConstraints as X509NameConstraints};
+use x509_parser::oid_registry::{
+    Oid, OID_X509_COMMON_NAME, OID_X509_COUNTRY_NAME, OID_X509_LOCALITY_NAME,
+    OID_X509_ORGANIZATIONAL_UNIT, OID_X509_ORGANIZATION_NAME, OID_X509_STATE_OR_PROVINCE_NAME,
+};
+use x509_parser::prelude::FromDer;
+use yasna::models::ObjectIdentifier;
+
+use crate::Error;
+
+/// The name constraints of a root: the subtrees of names it may (and may not) issue for.
+///
+/// This can be parsed from the text form used for the CCADB "Mozilla Applied Constraints"
+/// (see [`NameConstraints::from_str()`]), and converted to and from the DER form expected by
+/// the webpki crate's `TrustAnchor` representation (see [`NameConstraints::to_der()`]).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct NameConstraints {
+    /// The subtrees names must be within (if not empty).
+    pub permitted: Vec<GeneralSubtree>,
+    /// The subtrees names must not be within.
+    pub excluded: Vec<GeneralSubtree>,
+}
+
+impl NameConstraints {
+    /// Create name constraints with the given `permitted` and `excluded` subtrees.
+    pub fn new(permitted: Vec<GeneralSubtree>, excluded: Vec<GeneralSubtree>) -> Self {
+        Self {
+            permitted,
+            excluded,
+        }
+    }
+
+    /// Returns true if there are no permitted or excluded subtrees.
+    pub fn is_empty(&self) -> bool {
+        self.permitted.is_empty() && self.excluded.is_empty()
+    }
+
+    /// Combine these name constraints with `other` into ones that only permit the names
+    /// permitted by both.
+    ///
+    /// Excluded subtrees are merged. Permitted subtrees of a kind constrained by only one of the
+    /// two are kept; for a kind constrained by both, only the subtrees within a subtree of the
+    /// other are kept. Returns an error if that leaves no permitted subtrees of such a kind, as
+    /// no subtrees would permit every name of that kind.
+    pub fn intersect(&self, other: &Self) -> Result<Self, Error> {
+        let mut permitted = Vec::<GeneralSubtree>::new();
+        for (subtrees, others) in
+            [(self, other), (other, self)].map(|(a, b)| (&a.permitted, &b.permitted))
+        {
+            for subtree in subtrees {
+                let mut same_kind = others.iter().filter(|other| other.same_kind(subtree));
+                let keep = match same_kind.clone().next() {
+                    None => true,
+                    Some(_) => same_kind.any(|other| subtree.is_within(other)),
+                };
+                if keep && !permitted.contains(subtree) {
+                    permitted.push(subtree.clone());
+                }
+            }
+        }
+
+        for subtree in &self.permitted {
+            let constrained_by_both = other.permitted.iter().any(|o| o.same_kind(subtree));
+            if constrained_by_both && !permitted.iter().any(|kept| kept.same_kind(subtree)) {
+                return Err(Error::InvalidNameConstraints(format!(
+                    "no names like {subtree} are permitted by both {self} and {other}"
+                )));
+            }
+        }
+
+        let mut excluded = self.excluded.clone();
+        for subtree in &other.excluded {
+            if !excluded.contains(subtree) {
+                excluded.push(subtree.clone());
+            }
+        }
+        Ok(Self {
+            permitted,
+            excluded,
+        })
+    }
+
+    /// Parse name constraints in the DER form expected by the webpki crate's `TrustAnchor`
+    /// representation.
+    ///
+    /// Directory name attributes keep the string type they are encoded with, and the host bits
+    /// of IP subtrees are cleared, so [`NameConstraints::to_der()`] may not return exactly `der`.
+    ///
+    /// Returns an error if the DER is invalid, has subtrees of a kind not supported by
+    /// [`GeneralSubtree`], or has a subtree with a minimum or maximum (which RFC 5280 requires
+    /// to be absent).
+    pub fn from_der(der: &[u8]) -> Result<Self, Error> {
+        let invalid = |reason: &str| Error::InvalidNameConstraints(reason.to_string());
+
+        // The webpki representation elides the outer NameConstraints SEQUENCE, so wrap it up.
+        let wrapped_der = yasna::construct_der(|writer| {
+            writer.write_sequence(|writer| writer.next().write_der(der))
+        });
+        // x509-parser skips what it does not understand within the SEQUENCE, so check that the
+        // encoding is well formed first.
+        if !matches!(
+            x509_parser::der_parser::parse_der(&wrapped_der),
+            Ok((&[], _))
+        ) {
+            return Err(invalid("invalid DER encoding"));
+        }
+        let (trailing, constraints) = X509NameConstraints::from_der(&wrapped_der)
+            .map_err(|_| invalid("invalid DER encoding"))?;
+        if !trailing.is_empty() {
+            return Err(invalid("trailing data after DER encoding"));
+        }
+        // x509-parser also drops the minimum and maximum of each subtree.
+        if has_subtree_bounds(&wrapped_der).map_err(|_| invalid("invalid DER encoding"))? {
+            return Err(invalid("subtree minimum and maximum are not supported"));
+        }
+
+        let subtrees = |subtrees: Option<Vec<x509_parser::extensions::GeneralSubtree<'_>>>| {
+            subtrees
+                .unwrap_or_default()
+                .iter()
+                .map(|subtree| GeneralSubtree::from_general_name(&subtree.base))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            permitted: subtrees(constraints.permitted_subtrees)?,
+            excluded: subtrees(constraints.excluded_subtrees)?,
+        })
+    }
+
+    /// Encode the name constraints in the DER form expected by the webpki crate's
+    /// `TrustAnchor` representation.
+    pub fn to_der(&self) -> Vec<u8> {
+        // Important: the webpki representation of name constraints elides:
+        //   - the outer BITSTRING of the X.509 extension value.
+        //   - the outer NameConstraints SEQUENCE over the permitted/excluded subtrees.
+        //
+        // See https://www.rfc-editor.org/rfc/rfc5280#section-4.2.1.10
+        let mut der = Vec::new();
+        // permittedSubtrees [0], excludedSubtrees [1]
+        for (tag, subtrees) in [(0, &self.permitted), (1, &self.excluded)] {
+            if subtrees.is_empty() {
+                continue;
+            }
+
+            der.extend(yasna::construct_der(|writer| {
+                writer.write_tagged_implicit(yasna::Tag::context(tag), |writer| {
+                    // GeneralSubtrees
+                    writer.write_sequence(|writer| {
+                        for subtree in subtrees {
+                            // base GeneralName
+                            // minimum [0] (absent, 0 default)
+                            // maximum [1] (must be omitted).
+                            writer
+                                .next()
+                                .write_sequence(|writer| subtree.write_der(writer.next()));
+                        }
+                    })
+                })
+            }));
+        }
+        der
+    }
+}
+
+/// Parses the text form of name constraints used for the CCADB "Mozilla Applied Constraints".
+///
+/// The text is a comma separated list of subtrees, each optionally prefixed by `permitted;`
+/// (the default) or `excluded;`. Each subtree is one of:
+///
+/// - `DNS:name`, e.g. `DNS:.tr`. A bare name (e.g. `*.tr`) is also accepted as a DNS subtree.
+///   A leading `*.` means the same as a leading `.`, so `*.tr` is `.tr`; any other `*` is an
+///   error.
+/// - `IP:address/prefix length`, e.g. `IP:192.0.2.0/24` or `IP:2001:db8::/32`.
+/// - `dirName:` followed by `/` separated attributes, e.g. `dirName:C=TR/O=Example`. The
+///   supported attributes are `C`, `ST`, `L`, `O`, `OU` and `CN`. `C` is encoded as a
+///   `PrintableString` and the others as `UTF8String`s.
+///
+/// Only bare DNS names appear in the CCADB report today; the other forms are this crate's own
+/// syntax. The host bits of IP subtrees are cleared. Returns an error for any other syntax.
+impl FromStr for NameConstraints {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut constraints = Self::default();
+        for entry in s.split(',').map(str::trim) {
+            let (subtrees, subtree) = if let Some(subtree) = entry.strip_prefix("permitted;") {
+                (&mut constraints.permitted, subtree)
+            } else if let Some(subtree) = entry.strip_prefix("excluded;") {
+                (&mut constraints.excluded, subtree)
+            } else {
+                (&mut constraints.permitted, entry)
+            };
+            subtrees.push(subtree.trim().parse()?);
+        }
+        Ok(constraints)
+    }
+}
+
+/// Formats the name constraints in the text form parsed by [`NameConstraints::from_str()`].
+impl fmt::Display for NameConstraints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let subtrees = self
+            .permitted
+            .iter()
+            .map(|subtree| ("permitted", subtree))
+            .chain(self.excluded.iter().map(|subtree| ("excluded", subtree)));
+        for (index, (kind, subtree)) in subtrees.enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{kind};{subtree}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A subtree of names in [`NameConstraints`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GeneralSubtree {
+    /// DNS names equal to, or ending with, the given name, e.g. `.tr`.
+    DnsName(String),
+    /// IP addresses within the given network.
+    IpAddress {
+        /// The network address.
+        address: IpAddr,
+        /// The length of the network prefix in bits.
+        prefix_len: u8,
+    },
+    /// Directory names starting with the given attributes, in order.
+    DirectoryName(Vec<(NameAttribute, DirectoryString)>),
+}
+
+impl GeneralSubtree {
+    /// Returns true if every name in this subtree is also in `other`.
+    ///
+    /// A DNS subtree `x` holds `x` and its subdomains, while `.x` holds only the subdomains of
+    /// `x`, and the empty DNS subtree holds every DNS name. A directory name subtree holds the
+    /// names starting with its attributes.
+    pub fn is_within(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::DnsName(name), Self::DnsName(other)) => dns_name_is_within(name, other),
+            (
+                Self::IpAddress {
+                    address,
+                    prefix_len,
+                },
+                Self::IpAddress {
+                    address: other_address,
+                    prefix_len: other_prefix_len,
+                },
+            ) => {
+                address.is_ipv4() == other_address.is_ipv4()
+                    && prefix_len >= other_prefix_len
+                    && network_address(*address, *other_prefix_len) == *other_address
+            }
+            (Self::DirectoryName(attributes), Self::DirectoryName(other)) => {
+                attributes.starts_with(other)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if `other` constrains the same kind of name (the same `GeneralName` type).
+    fn same_kind(&self, other: &Self) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+
+    fn from_general_name(name: &GeneralName<'_>) -> Result<Self, Error> {
+        let invalid = |reason: String| Error::InvalidNameConstraints(reason);
+        match name {
+            GeneralName::DNSName(name) => Ok(Self::DnsName(name.to_string())),
+            GeneralName::IPAddress(bytes) => {
+                let (address, mask) = match bytes.len() {
+                    8 => {
+                        let address = <[u8; 4]>::try_from(&bytes[..4]).unwrap();
+                        (IpAddr::from(address), &bytes[4..])
+                    }
+                    32 => {
+                        let address = <[u8; 16]>::try_from(&bytes[..16]).unwrap();
+                        (IpAddr::from(address), &bytes[16..])
+                    }
+                    len => return Err(invalid(format!("invalid IP address length {len}"))),
+                };
+                let prefix_len = prefix_len(mask)
+                    .ok_or_else(|| invalid(format!("non-contiguous IP mask {mask:?}")))?;
+                Ok(Self::IpAddress {
+                    address: network_address(address, prefix_len),
+                    prefix_len,
+                })
+            }
+            GeneralName::DirectoryName(name) => {
+                let mut attributes = Vec::new();
+                for rdn in name.iter() {
+                    let mut iter = rdn.iter();
+                    let (Some(attribute), None) = (iter.next(), iter.next()) else {
+                        return Err(invalid(format!("unsupported directory name {name}")));
+                    };
+                    let typ = NameAttribute::from_oid(attribute.attr_type()).ok_or_else(|| {
+                        invalid(format!("unsupported attribute {}", attribute.attr_type()))
+                    })?;
+                    let value = attribute.attr_value();
+                    let value = DirectoryString::from_der(value.tag().0, value.data)
+                        .ok_or_else(|| invalid(format!("unsupported directory name {name}")))?;
+                    attributes.push((typ, value));
+                }
+                Ok(Self::DirectoryName(attributes))
+            }
+            name => Err(invalid(format!("unsupported subtree {name}"))),
+        }
+    }
+
+    fn write_der(&self, writer: yasna::DERWriter<'_>) {
+        match self {
+            // dNSName [2] IMPLICIT IA5String
+            Self::DnsName(name) => writer.write_tagged_implicit(yasna::Tag::context(2), |writer| {
+                writer.write_ia5_string(name)
+            }),
+            // iPAddress [7] IMPLICIT OCTET STRING: the address followed by the mask.
+            Self::IpAddress {
+                address,
+                prefix_len,
+            } => {
+                let mut bytes = match address {
+                    IpAddr::V4(address) => address.octets().to_vec(),
+                    IpAddr::V6(address) => address.octets().to_vec(),
+                };
+                let bits = bytes.len() * 8;
+                let mask = match *prefix_len {
+                    0 => 0,
+                    len => u128::MAX << (128 - usize::from(len).min(bits)),
+                };
+                bytes.extend_from_slice(&mask.to_be_bytes()[..bits / 8]);
+                writer.write_tagged_implicit(yasna::Tag::context(7), |writer| {
+                    writer.write_bytes(&bytes)
+                })
+            }
+            // directoryName [4] EXPLICIT Name
+            Self::DirectoryName(attributes) => {
+                writer.write_tagged(yasna::Tag::context(4), |writer| {
+                    writer.write_sequence(|writer| {
+                        for (typ, value) in attributes {
+                            // RelativeDistinguishedName
+                            writer.next().write_set(|writer| {
+                                // AttributeTypeAndValue
+                                writer.next().write_sequence(|writer| {
+                                    let oid = typ.oid().iter().unwrap().collect::<Vec<_>>();
+                                    writer.next().write_oid(&ObjectIdentifier::from_slice(&oid));
+                                    value.write_der(writer.next());
+                                })
+                            })
+                        }
+                    })
+                })
+            }
+        }
+    }
+}
+
+impl FromStr for GeneralSubtree {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidNameConstraints(s.to_string());
+        if let Some(network) = s.strip_prefix("IP:") {
+            let (address, prefix_len) = network.split_once('/').ok_or_else(invalid)?;
+            let address = IpAddr::from_str(address).map_err(|_| invalid())?;
+            let prefix_len = u8::from_str(prefix_len).map_err(|_| invalid())?;
+            let max = match address {
+                IpAddr::V4(_) => 32,
+                IpAddr::V6(_) => 128,
+            };
+            return match prefix_len <= max {
+                true => Ok(Self::IpAddress {
+                    address: network_address(address, prefix_len),
+                    prefix_len,
+                }),
+                false => Err(invalid()),
+            };
+        }
+
+        if let Some(name) = s.strip_prefix("dirName:") {
+            let attributes = name
+                .split('/')
+                .map(|attribute| {
+                    let (typ, value) = attribute.split_once('=').ok_or_else(invalid)?;
+                    let typ = NameAttribute::from_str(typ.trim()).map_err(|_| invalid())?;
+                    match value.trim() {
+                        "" => Err(invalid()),
+                        value => Ok((typ, DirectoryString::new(typ, value.to_string()))),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(Self::DirectoryName(attributes));
+        }
+
+        // Bare names are DNS names, as in the original CCADB format (e.g. `*.tr`).
+        let name = s.strip_prefix("DNS:").unwrap_or(s);
+        // `*.tr` is the subtree `.tr`. A `*` anywhere else is rejected as an invalid character.
+        let name = name
+            .strip_prefix('*')
+            .filter(|domain| domain.starts_with('.'))
+            .unwrap_or(name);
+        let valid = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '.';
+        match !matches!(name, "" | ".") && name.chars().all(valid) {
+            true => Ok(Self::DnsName(name.to_string())),
+            false => Err(invalid()),
+        }
+    }
+}
+
+/// Formats the subtree in the text form parsed by [`GeneralSubtree::from_str()`].
+impl fmt::Display for GeneralSubtree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DnsName(name) => write!(f, "DNS:{name}"),
+            Self::IpAddress {
+                address,
+                prefix_len,
+            } => write!(f, "IP:{address}/{prefix_len}"),
+            Self::DirectoryName(attributes) => {
+                f.write_str("dirName:")?;
+                for (index, (typ, value)) in attributes.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str("/")?;
+                    }
+                    write!(f, "{typ}={value}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An attribute of a directory name in a [`GeneralSubtree::DirectoryName`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum NameAttribute {
+    /// The country name (`C`).
+    Country,
+    /// The state or province name (`ST`).
+    StateOrProvince,
+    /// The locality name (`L`).
+    Locality,
+    /// The organization name (`O`).
+    Organization,
+    /// The organizational unit name (`OU`).
+    OrganizationalUnit,
+    /// The common name (`CN`).
+    CommonName,
+}
+
+impl NameAttribute {
+    const ALL: [Self; 6] = [
+        Self::Country,
+        Self::StateOrProvince,
+        Self::Locality,
+        Self::Organization,
+        Self::OrganizationalUnit,
+        Self::CommonName,
+    ];
+
+    fn from_oid(oid: &Oid<'_>) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|attribute| attribute.oid() == *oid)
+    }
+
+    fn oid(&self) -> Oid<'static> {
+        match self {
+            Self::Country => OID_X509_COUNTRY_NAME,
+            Self::StateOrProvince => OID_X509_STATE_OR_PROVINCE_NAME,
+            Self::Locality => OID_X509_LOCALITY_NAME,
+            Self::Organization => OID_X509_ORGANIZATION_NAME,
+            Self::OrganizationalUnit => OID_X509_ORGANIZATIONAL_UNIT,
+            Self::CommonName => OID_X509_COMMON_NAME,
+        }
+    }
+
+    fn short_name(&self) -> &'static str {
+        match self {
+            Self::Country => "C",
+            Self::StateOrProvince => "ST",
+            Self::Locality => "L",
+            Self::Organization => "O",
+            Self::OrganizationalUnit => "OU",
+            Self::CommonName => "CN",
+        }
+    }
+}
+
+impl FromStr for NameAttribute {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|attribute| attribute.short_name() == s)
+            .ok_or_else(|| Error::InvalidNameConstraints(format!("unsupported attribute {s:?}")))
+    }
+}
+
+impl fmt::Display for NameAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.short_name())
+    }
+}
+
+/// The value of a directory name attribute in a [`GeneralSubtree::DirectoryName`], with the
+/// ASN.1 string type it is encoded as.
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum DirectoryString {
+    /// A `PrintableString`.
+    Printable(String),
+    /// A `UTF8String`.
+    Utf8(String),
+    /// An `IA5String`.
+    Ia5(String),
+    /// A `BMPString`, encoded as UTF-16.
+    Bmp(String),
+    /// A `UniversalString`, encoded as UTF-32.
+    Universal(String),
+}
+
+impl DirectoryString {
+    /// Returns the value of `attribute` in the text form of name constraints, with the string
+    /// type it is encoded as: a `PrintableString` for the country, and a `UTF8String` otherwise.
+    pub fn new(attribute: NameAttribute, value: String) -> Self {
+        match attribute {
+            NameAttribute::Country => Self::Printable(value),
+            _ => Self::Utf8(value),
+        }
+    }
+
+    /// Returns the value of the string.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Printable(value)
+            | Self::Utf8(value)
+            | Self::Ia5(value)
+            | Self::Bmp(value)
+            | Self::Universal(value) => value,
+        }
+    }
+
+    /// Decode the contents of a string with the given universal tag number.
+    fn from_der(tag: u32, contents: &[u8]) -> Option<Self> {
+        let utf8 = || String::from_utf8(contents.to_vec()).ok();
+        match tag {
+            PRINTABLE_STRING => utf8().map(Self::Printable),
+            UTF8_STRING => utf8().map(Self::Utf8),
+            IA5_STRING => utf8().map(Self::Ia5),
+            BMP_STRING => {
+                let units = contents.chunks_exact(2);
+                if !units.remainder().is_empty() {
+                    return None;
+                }
+                let units = units.map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+                char::decode_utf16(units)
+                    .collect::<Result<_, _>>()
+                    .ok()
+                    .map(Self::Bmp)
+            }
+            UNIVERSAL_STRING => {
+                let chars = contents.chunks_exact(4);
+                if !chars.remainder().is_empty() {
+                    return None;
+                }
+                chars
+                    .map(|c| char::from_u32(u32::from_be_bytes([c[0], c[1], c[2], c[3]])))
+                    .collect::<Option<_>>()
+                    .map(Self::Universal)
+            }
+            _ => None,
+        }
+    }
+
+    fn write_der(&self, writer: yasna::DERWriter<'_>) {
+        let (tag, contents) = match self {
+            Self::Printable(value) => (PRINTABLE_STRING, value.as_bytes().to_vec()),
+            Self::Utf8(value) => (UTF8_STRING, value.as_bytes().to_vec()),
+            Self::Ia5(value) => (IA5_STRING, value.as_bytes().to_vec()),
+            Self::Bmp(value) => (
+                BMP_STRING,
+                value.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+            ),
+            Self::Universal(value) => (
+                UNIVERSAL_STRING,
+                value
+                    .chars()
+                    .flat_map(|c| u32::from(c).to_be_bytes())
+                    .collect(),
+            ),
+        };
+        let tag = yasna::Tag {
+            tag_class: yasna::TagClass::Universal,
+            tag_number: u64::from(tag),
+        };
+        writer.write_tagged_implicit(tag, |writer| writer.write_bytes(&contents));
+    }
+}
+
+impl fmt::Display for DirectoryString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+const UTF8_STRING: u32 = 12;
+const PRINTABLE_STRING: u32 = 19;
+const IA5_STRING: u32 = 22;
+const UNIVERSAL_STRING: u32 = 28;
+const BMP_STRING: u32 = 30;
+
+/// Returns `address` with the bits after the first `prefix_len` cleared.
+fn network_address(address: IpAddr, prefix_len: u8) -> IpAddr {
+    let len = u32::from(prefix_len);
+    match address {
+        IpAddr::V4(address) => {
+            let mask = u32::MAX.checked_shl(32 - len.min(32)).unwrap_or(0);
+            IpAddr::from(Ipv4Addr::from(u32::from(address) & mask))
+        }
+        IpAddr::V6(address) => {
+            let mask = u128::MAX.checked_shl(128 - len.min(128)).unwrap_or(0);
+            IpAddr::from(Ipv6Addr::from(u128::from(address) & mask))
+        }
+    }
+}
+
+fn dns_name_is_within(name: &str, other: &str) -> bool {
+    let ends_with = |name: &str, suffix: &str| {
+        name.len() >= suffix.len()
+            && name.as_bytes()[name.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+    };
+
+    if other.is_empty() || name.eq_ignore_ascii_case(other) {
+        return true;
+    }
+    match other.starts_with('.') {
+        true => ends_with(name, other),
+        false => ends_with(name, other) && name.as_bytes()[name.len() - other.len() - 1] == b'.',
+    }
+}
+
+/// Returns true if any subtree in the wrapped NameConstraints `der` has a minimum or maximum.
+fn has_subtree_bounds(der: &[u8]) -> Result<bool, yasna::ASN1Error> {
+    let mut bounded = false;
+    yasna::parse_der(der, |reader| {
+        reader.read_sequence(|reader| {
+            // permittedSubtrees [0], excludedSubtrees [1]
+            for tag in [0, 1] {
+                reader.read_optional(|reader| {
+                    reader.read_tagged_implicit(yasna::Tag::context(tag), |reader| {
+                        reader.read_sequence_of(|reader| {
+                            // base GeneralName, followed by minimum [0] and maximum [1] if present.
+                            reader.read_sequence(|reader| {
+                                reader.next().read_der()?;
+                                while reader.read_optional(|reader| reader.read_der())?.is_some() {
+                                    bounded = true;
+                                }
+                                Ok(())
+                            })
+                        })
+                    })
+                })?;
+            }
+            Ok(())
+        })
+    })?;
+    Ok(bounded)
+}
+
+/// Returns the length of the prefix of set bits in `mask`, if the rest are all unset.
+fn prefix_len(mask: &[u8]) -> Option<u8> {
+    let mask = mask
+        .iter()
+        .fold(0u128, |acc, &byte| acc << 8 | u128::from(byte))
+        << (128 - mask.len() * 8);
+    let len = mask.leading_ones();
+    (mask.checked_shl(len).unwrap_or(0) == 0).then_some(len as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_dns_constraints() {
+        // The original format: comma separated permitted DNS names, with a leading `*`.
+        let constraints = NameConstraints::from_str("*.tr").unwrap();
+        assert_eq!(
+            constraints,
+            NameConstraints::new(vec![GeneralSubtree::DnsName(".tr".to_string())], vec![])
+        );
+        assert_eq!(constraints.to_der(), b"\xa0\x07\x30\x05\x82\x03.tr");
+        assert_eq!(constraints.to_string(), "permitted;DNS:.tr");
+
+        let constraints = NameConstraints::from_str("*.tr, .example").unwrap();
+        assert_eq!(constraints.permitted.len(), 2);
+        assert!(constraints.excluded.is_empty());
+
+        // Only a leading `*.` is a wildcard.
+        for text in ["*foo.tr", "DNS:*bad", "foo.*.tr", "*", "*."] {
+            assert!(
+                matches!(
+                    NameConstraints::from_str(text),
+                    Err(Error::InvalidNameConstraints(_))
+                ),
+                "{text:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixed_constraints_round_trip() {
+        let text = concat!(
+            "permitted;DNS:.example, ",
+            "permitted;IP:192.0.2.0/24, ",
+            "permitted;IP:2001:db8::/32, ",
+            "permitted;dirName:C=TR/O=Example Org/CN=Example CA, ",
+            "excluded;DNS:bad.example, ",
+            "excluded;IP:0.0.0.0/0"
+        );
+        let constraints = NameConstraints::from_str(text).unwrap();
+        assert_eq!(
+            constraints,
+            NameConstraints::new(
+                vec![
+                    GeneralSubtree::DnsName(".example".to_string()),
+                    GeneralSubtree::IpAddress {
+                        address: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0)),
+                        prefix_len: 24,
+                    },
+                    GeneralSubtree::IpAddress {
+                        address: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)),
+                        prefix_len: 32,
+                    },
+                    GeneralSubtree::DirectoryName(vec![
+                        (
+                            NameAttribute::Country,
+                            DirectoryString::Printable("TR".to_string())
+                        ),
+                        (
+                            NameAttribute::Organization,
+                            DirectoryString::Utf8("Example Org".to_string())
+                        ),
+                        (
+                            NameAttribute::CommonName,
+                            DirectoryString::Utf8("Example CA".to_string())
+                        ),
+                    ]),
+                ],
+                vec![
+                    GeneralSubtree::DnsName("bad.example".to_string()),
+                    GeneralSubtree::IpAddress {
+                        address: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                        prefix_len: 0,
+                    },
+                ],
+            )
+        );
+        assert_eq!(constraints.to_string(), text);
+
+        let der = constraints.to_der();
+        assert_eq!(NameConstraints::from_der(&der).unwrap(), constraints);
+
+        // The IP subtree is encoded as the address followed by the mask.
+        let ip = GeneralSubtree::from_str("IP:192.0.2.0/24").unwrap();
+        let der = NameConstraints::new(vec![ip], vec![]).to_der();
+        assert!(der.ends_with(b"\x87\x08\xc0\x00\x02\x00\xff\xff\xff\x00"));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let tr = NameConstraints::from_str("*.tr").unwrap();
+        let overlay =
+            NameConstraints::from_str("DNS:gov.tr, IP:192.0.2.0/24, excluded;DNS:bad.gov.tr")
+                .unwrap();
+        assert_eq!(
+            tr.intersect(&overlay).unwrap(),
+            NameConstraints::from_str("DNS:gov.tr, IP:192.0.2.0/24, excluded;DNS:bad.gov.tr")
+                .unwrap()
+        );
+        assert_eq!(
+            overlay.intersect(&tr).unwrap(),
+            tr.intersect(&overlay).unwrap()
+        );
+
+        // Without permitted subtrees in common, no DNS names would be permitted.
+        let example = NameConstraints::from_str("DNS:.example").unwrap();
+        assert!(matches!(
+            tr.intersect(&example),
+            Err(Error::InvalidNameConstraints(_))
+        ));
+
+        let dir_name = NameConstraints::from_str("dirName:C=TR/O=Example").unwrap();
+        let country = NameConstraints::from_str("dirName:C=TR").unwrap();
+        assert_eq!(dir_name.intersect(&country).unwrap(), dir_name);
+    }
+
+    #[test]
+    fn test_is_within() {
+        let subtree = |text: &str| GeneralSubtree::from_str(text).unwrap();
+        let dns = |name: &str| GeneralSubtree::DnsName(name.to_string());
+
+        assert!(subtree("DNS:gov.tr").is_within(&subtree("DNS:tr")));
+        assert!(subtree("DNS:gov.tr").is_within(&subtree("DNS:.tr")));
+        assert!(subtree("DNS:TR").is_within(&subtree("DNS:tr")));
+        assert!(!subtree("DNS:tr").is_within(&subtree("DNS:.tr")));
+        assert!(!subtree("DNS:xtr").is_within(&subtree("DNS:tr")));
+        // The empty DNS name permits every DNS name.
+        assert!(subtree("DNS:gov.tr").is_within(&dns("")));
+        assert!(dns("").is_within(&dns("")));
+        assert!(!dns("").is_within(&subtree("DNS:tr")));
+
+        assert!(subtree("IP:192.0.2.128/25").is_within(&subtree("IP:192.0.2.0/24")));
+        assert!(!subtree("IP:192.0.2.0/23").is_within(&subtree("IP:192.0.2.0/24")));
+        assert!(!subtree("IP:::/0").is_within(&subtree("IP:0.0.0.0/0")));
+        assert!(!subtree("IP:192.0.2.0/24").is_within(&subtree("DNS:tr")));
+    }
+
+    #[test]
+    fn test_lenient_der() {
+        // permitted: dirName O=Example as a PrintableString, and 192.0.2.1/24 with host bits.
+        let dir_name =
+            b"\x30\x16\xa4\x14\x30\x12\x31\x10\x30\x0e\x06\x03\x55\x04\x0a\x13\x07Example";
+        let ip = b"\x30\x0a\x87\x08\xc0\x00\x02\x01\xff\xff\xff\x00";
+        let der = [&b"\xa0\x24"[..], dir_name, ip].concat();
+        let constraints = NameConstraints::from_der(&der).unwrap();
+        assert_eq!(
+            constraints.permitted,
+            [
+                GeneralSubtree::DirectoryName(vec![(
+                    NameAttribute::Organization,
+                    DirectoryString::Printable("Example".to_string())
+                )]),
+                GeneralSubtree::IpAddress {
+                    address: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0)),
+                    prefix_len: 24,
+                },
+            ]
+        );
+        assert_eq!(
+            constraints.to_string(),
+            "permitted;dirName:O=Example, permitted;IP:192.0.2.0/24"
+        );
+
+        // The string type is kept when re-encoding.
+        let der = [&b"\xa0\x18"[..], dir_name].concat();
+        assert_eq!(NameConstraints::from_der(&der).unwrap().to_der(), der);
+
+        // Wider string types are decoded too.
+        assert_eq!(
+            DirectoryString::from_der(BMP_STRING, b"\x00T\x00R"),
+            Some(DirectoryString::Bmp("TR".to_string()))
+        );
+        assert_eq!(
+            DirectoryString::from_der(UNIVERSAL_STRING, b"\x00\x00\x00T\x00\x00\x00R"),
+            Some(DirectoryString::Universal("TR".to_string()))
+        );
+        assert_eq!(DirectoryString::from_der(BMP_STRING, b"\x00T\x00"), None);
+
+        // Text is normalised the same way.
+        assert_eq!(
+            GeneralSubtree::from_str("IP:2001:db8::1/32").unwrap(),
+            GeneralSubtree::IpAddress {
+                address: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)),
+                prefix_len: 32,
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_constraints() {
+        for text in [
+            "",
+            "DNS:",
+            "DNS:exa mple",
+            "email:user@example.com",
+            "permitted;URI:https://example.com",
+            "IP:192.0.2.0",
+            "IP:192.0.2.0/33",
+            "IP:2001:db8::/129",
+            "IP:example/8",
+            "dirName:C=TR/X=Unknown",
+            "dirName:C=",
+            "*.tr,",
+        ] {
+            assert!(
+                matches!(
+                    NameConstraints::from_str(text),
+                    Err(Error::InvalidNameConstraints(_))
+                ),
+                "{text:?} should be rejected"
+            );
+        }
+
+        // A non-contiguous IP mask can not be expressed as a prefix length.
+        let der = b"\xa0\x0c\x30\x0a\x87\x08\xc0\x00\x02\x00\xff\x00\xff\x00";
+        assert!(matches!(
+            NameConstraints::from_der(der),
+            Err(Error::InvalidNameConstraints(_))
+        ));
+        assert!(NameConstraints::from_der(b"\xa0").is_err());
+
+        // A subtree minimum or maximum is rejected rather than dropped.
+        for bounds in [
+            &b"\x80\x01\x01"[..],
+            b"\x81\x01\x02",
+            b"\x80\x01\x01\x81\x01\x02",
+        ] {
+            let subtree = [b"\x82\x03.tr", bounds].concat();
+            let der = [
+                &[0xa0, subtree.len() as u8 + 2, 0x30, subtree.len() as u8],
+                &subtree[..],
+            ]
+            .concat();
+            assert!(matches!(
+                NameConstraints::from_der(&der),
+                Err(Error::InvalidNameConstraints(reason)) if reason.contains("minimum")
+            ));
+        }
+        let der = b"\xa0\x07\x30\x05\x82\x03.tr";
+        assert!(NameConstraints::from_der(der).is_ok());
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::CertificateMetadata;
+
+/// Compare two sets of roots, as returned by [`crate::TrustPolicy::apply()`].
+///
+/// Roots are matched by SHA-256 fingerprint, which also identifies the certificate itself. A
+/// root in both sets is modified if any of its other CCADB metadata differs between them.
+pub fn diff(
+    old: &BTreeMap<String, CertificateMetadata>,
+    new: &BTreeMap<String, CertificateMetadata>,
+) -> RootStoreDiff {
+    let mut diff = RootStoreDiff::default();
+    for (fp, old_root) in old {
+        let Some(new_root) = new.get(fp) else {
+            diff.removed.push(RootSummary::new(old_root));
+            continue;
+        };
+
+        let changes = FIELDS
+            .iter()
+            .filter_map(|&(field, value)| {
+                let (old, new) = (value(old_root), value(new_root));
+                (old != new).then(|| FieldChange {
+                    field,
+                    old: old.to_string(),
+                    new: new.to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+        if !changes.is_empty() {
+            diff.modified.push(ModifiedRoot {
+                root: RootSummary::new(new_root),
+                changes,
+            });
+        }
+    }
+
+    diff.added = new
+        .iter()
+        .filter(|(fp, _)| !old.contains_key(*fp))
+        .map(|(_, root)| RootSummary::new(root))
+        .collect();
+    diff
+}
+
+/// The changes between two sets of roots, as returned by [`diff()`].
+///
+/// Each list is ordered by SHA-256 fingerprint.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
+pub struct RootStoreDiff {
+    /// The roots only in the new set.
+    pub added: Vec<RootSummary>,
+    /// The roots only in the old set.
+    pub removed: Vec<RootSummary>,
+    /// The roots in both sets whose metadata changed.
+    pub modified: Vec<ModifiedRoot>,
+}
+
+impl RootStoreDiff {
+    /// Returns true if no roots were added, removed or modified.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+
+    /// Render the changes as Markdown, e.g. for release notes.
+    pub fn to_markdown(&self) -> String {
+        if self.is_empty() {
+            return "No changes to the roots.\n".to_string();
+        }
+
+        let mut md = String::new();
+        for (heading, roots) in [
+            ("Added roots", &self.added),
+            ("Removed roots", &self.removed),
+        ] {
+            if roots.is_empty() {
+                continue;
+            }
+
+            writeln!(md, "## {heading}\n").unwrap();
+            for root in roots {
+                writeln!(md, "- {}", root.to_markdown()).unwrap();
+            }
+            md.push('\n');
+        }
+
+        if !self.modified.is_empty() {
+            md.push_str("## Modified roots\n\n");
+            for modified in &self.modified {
+                writeln!(md, "- {}", modified.root.to_markdown()).unwrap();
+                for change in &modified.changes {
+                    writeln!(
+                        md,
+                        "  - {}: {} → {}",
+                        change.field,
+                        markdown_value(&change.old),
+                        markdown_value(&change.new)
+                    )
+                    .unwrap();
+                }
+            }
+            md.push('\n');
+        }
+
+        md.truncate(md.trim_end().len());
+        md.push('\n');
+        md
+    }
+
+    /// Render the changes as a JSON object, e.g. for automated review.
+    ///
+    /// The object has `added`, `removed` and `modified` arrays. Each root is an object with
+    /// `sha256_fingerprint`, `label` and `owner` strings, and each modified root also has a
+    /// `changes` array of objects with `field`, `old` and `new` strings.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("diffs always serialize")
+    }
+}
+
+/// Identifies a root in a [`RootStoreDiff`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct RootSummary {
+    /// The hex encoded SHA-256 fingerprint of the root certificate.
+    pub sha256_fingerprint: String,
+    /// The common name or certificate name label of the root.
+    pub label: String,
+    /// The CA operator that owns the root.
+    pub owner: String,
+}
+
+impl RootSummary {
+    fn new(root: &CertificateMetadata) -> Self {
+        Self {
+            sha256_fingerprint: root.sha256_fingerprint.clone(),
+            label: root.common_name_or_certificate_name.clone(),
+            owner: root.owner.clone(),
+        }
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut md = format!("**{}**", self.label);
+        if !self.owner.is_empty() {
+            write!(md, " ({})", self.owner).unwrap();
+        }
+        write!(md, ", SHA-256 `{}`", self.sha256_fingerprint).unwrap();
+        md
+    }
+}
+
+/// A root whose metadata changed, in a [`RootStoreDiff`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct ModifiedRoot {
+    /// The root, as described by the new metadata.
+    #[serde(flatten)]
+    pub root: RootSummary,
+    /// The fields that changed.
+    pub changes: Vec<FieldChange>,
+}
+
+/// A change to one CCADB field of a root.
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct FieldChange {
+    /// The name of the CCADB report column, e.g. "Distrust for TLS After Date".
+    pub field: &'static str,
+    /// The old value (empty if there was none).
+    pub old: String,
+    /// The new value (empty if there is none).
+    pub new: String,
+}
+
+fn markdown_value(value: &str) -> String {
+    match value.is_empty() {
+        true => "(none)".to_string(),
+        false => format!("`{}`", value.replace('`', "'")),
+    }
+}
+
+/// The compared CCADB fields, with their report column names.
+const FIELDS: &[(&str, FieldValue)] = &[
+    ("Owner", |root| &root.owner),
+    ("Common Name or Certificate Name", |root| {
+        &root.common_name_or_certificate_name
+    }),
+    ("Certificate Serial Number", |root| {
+        &root.certificate_serial_number
+    }),
+    ("Trust Bits", |root| &root.trust_bits),
+    ("Distrust for TLS After Date", |root| {
+        &root.distrust_for_tls_after_date
+    }),
+    ("Distrust for S/MIME After Date", |root| {
+        &root.distrust_for_smime_after_date
+    }),
+    ("Mozilla Applied Constraints", |root| {
+        &root.mozilla_applied_constraints
+    }),
+];
+
+type FieldValue = fn(&CertificateMetadata) -> &str;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_metadata;
+
+    #[test]
+    fn test_diff() {
+        let old = roots(&[("AA", "Kept"), ("BB", "Removed"), ("CC", "Modified")]);
+        let mut new = roots(&[("AA", "Kept"), ("CC", "Modified"), ("DD", "Added")]);
+        new.get_mut("CC").unwrap().distrust_for_tls_after_date = "2025.01.01".to_string();
+
+        let diff = diff(&old, &new);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].label, "Added");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].sha256_fingerprint, "BB");
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(
+            diff.modified[0].changes,
+            [FieldChange {
+                field: "Distrust for TLS After Date",
+                old: String::new(),
+                new: "2025.01.01".to_string(),
+            }]
+        );
+
+        assert_eq!(
+            diff.to_markdown(),
+            concat!(
+                "## Added roots\n\n",
+                "- **Added** (Owner \"DD\"), SHA-256 `DD`\n\n",
+                "## Removed roots\n\n",
+                "- **Removed** (Owner \"BB\"), SHA-256 `BB`\n\n",
+                "## Modified roots\n\n",
+                "- **Modified** (Owner \"CC\"), SHA-256 `CC`\n",
+                "  - Distrust for TLS After Date: (none) → `2025.01.01`\n",
+            )
+        );
+        assert_eq!(
+            diff.to_json(),
+            concat!(
+                r#"{"added":[{"sha256_fingerprint":"DD","label":"Added","owner":"Owner \"DD\""}],"#,
+                r#""removed":[{"sha256_fingerprint":"BB","label":"Removed","owner":"Owner \"BB\""}],"#,
+                r#""modified":[{"sha256_fingerprint":"CC","label":"Modified","owner":"Owner \"CC\"","#,
+                r#""changes":[{"field":"Distrust for TLS After Date","old":"","new":"2025.01.01"}]}]}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let old = roots(&[("AA", "Kept")]);
+        let diff = diff(&old, &old);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_markdown(), "No changes to the roots.\n");
+        assert_eq!(diff.to_json(), r#"{"added":[],"removed":[],"modified":[]}"#);
+    }
+
+    fn roots(roots: &[(&str, &str)]) -> BTreeMap<String, CertificateMetadata> {
+        roots
+            .iter()
+            .map(|&(fp, label)| {
+                let root = CertificateMetadata {
+                    owner: format!("Owner {fp:?}"),
+                    common_name_or_certificate_name: label.to_string(),
+                    sha256_fingerprint: fp.to_string(),
+                    ..test_metadata()
+                };
+                (fp.to_string(), root)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{CertificateMetadata, Error};
+
+/// Returns the roots in `roots` whose certificates expire within `within` of `at`, including
+/// any that have already expired, ordered by expiry time (soonest first).
+///
+/// `roots` is typically the result of [`crate::TrustPolicy::apply()`]. Returns an error if any
+/// root certificate can not be parsed.
+pub fn expiring_roots(
+    roots: &BTreeMap<String, CertificateMetadata>,
+    at: DateTime<Utc>,
+    within: Duration,
+) -> Result<Vec<ExpiringRoot>, Error> {
+    let mut expiring = Vec::new();
+    for root in roots.values() {
+        let not_after = root.not_after()?;
+        if not_after <= at + within {
+            expiring.push(ExpiringRoot {
+                sha256_fingerprint: root.sha256_fingerprint.clone(),
+                label: root.common_name_or_certificate_name.clone(),
+                owner: root.owner.clone(),
+                not_after,
+            });
+        }
+    }
+
+    expiring.sort_by(|a, b| {
+        (a.not_after, &a.sha256_fingerprint).cmp(&(b.not_after, &b.sha256_fingerprint))
+    });
+    Ok(expiring)
+}
+
+/// A root whose certificate expires soon, as returned by [`expiring_roots()`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExpiringRoot {
+    /// The hex encoded SHA-256 fingerprint of the root certificate.
+    pub sha256_fingerprint: String,
+    /// The common name or certificate name label of the root.
+    pub label: String,
+    /// The CA operator that owns the root.
+    pub owner: String,
+    /// The `notAfter` time of the root certificate.
+    pub not_after: DateTime<Utc>,
+}
+
+impl ExpiringRoot {
+    /// Returns true if the root certificate has expired at `at`.
+    pub fn is_expired_at(&self, at: DateTime<Utc>) -> bool {
+        self.not_after < at
+    }
+}
+
+impl fmt::Display for ExpiringRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?}",
+            self.not_after.format("%Y-%m-%d %H:%M:%S UTC"),
+            self.label
+        )?;
+        if !self.owner.is_empty() {
+            write!(f, " ({})", self.owner)?;
+        }
+        write!(f, ", SHA-256 {}", self.sha256_fingerprint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::TEST_ROOT_CSV;
+
+    #[test]
+    fn test_expiring_roots() {
+        let roots = crate::try_parse_ccadb_csv_str(TEST_ROOT_CSV).unwrap();
+        // DigiCert Global Root CA is valid until 2031-11-10 00:00:00 UTC.
+        let not_after = Utc.with_ymd_and_hms(2031, 11, 10, 0, 0, 0).unwrap();
+
+        let at = Utc.with_ymd_and_hms(2031, 1, 1, 0, 0, 0).unwrap();
+        assert!(expiring_roots(&roots, at, Duration::days(30))
+            .unwrap()
+            .is_empty());
+
+        let expiring = expiring_roots(&roots, at, Duration::days(365)).unwrap();
+        assert_eq!(expiring.len(), 1);
+        assert_eq!(expiring[0].not_after, not_after);
+        assert!(!expiring[0].is_expired_at(at));
+        assert_eq!(
+            expiring[0].to_string(),
+            "2031-11-10 00:00:00 UTC \"DigiCert Global Root CA\" (DigiCert), SHA-256 \
+             4348A0E9444C78CB265E058D5E8944B4D84F9662BD26DB257F8934A443C70161"
+        );
+
+        // Roots that have already expired are always included.
+        let at = Utc.with_ymd_and_hms(2032, 1, 1, 0, 0, 0).unwrap();
+        let expiring = expiring_roots(&roots, at, Duration::zero()).unwrap();
+        assert!(expiring[0].is_expired_at(at));
+    }
+}
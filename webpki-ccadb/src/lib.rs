@@ -1,87 +1,253 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashSet};
-use std::ops::Add;
+use std::fmt;
+use std::io::{self, Read};
+use std::str::FromStr;
 
-use chrono::{Duration, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use num_bigint::BigUint;
 use pki_types::pem::PemObject;
 use pki_types::CertificateDer;
 use serde::Deserialize;
 
-// Fetch root certificate data from the CCADB server.
-//
-// Returns an ordered BTreeMap of the root certificates, keyed by the SHA256 fingerprint of the
-// certificate. Panics if there are any duplicate fingerprints.
+#[cfg(feature = "fetch")]
+mod client;
+#[cfg(feature = "blocking")]
+pub use client::blocking;
+#[cfg(feature = "fetch")]
+pub use client::{CcadbClient, CcadbClientBuilder, DEFAULT_BASE_URL};
+
+pub mod codegen;
+
+mod constraints;
+pub use constraints::{DirectoryString, GeneralSubtree, NameAttribute, NameConstraints};
+
+mod diff;
+pub use diff::{diff, FieldChange, ModifiedRoot, RootStoreDiff, RootSummary};
+
+mod expiry;
+pub use expiry::{expiring_roots, ExpiringRoot};
+
+mod parsed;
+pub use parsed::ParsedRoot;
+
+mod policy;
+pub use policy::{TrustPolicy, DEFAULT_GRACE_PERIOD_DAYS, SMIME_GRACE_PERIOD_DAYS};
+
+mod snapshot;
+pub use snapshot::Snapshot;
+
+/// Fetch root certificate data from the CCADB server.
+///
+/// Returns an ordered BTreeMap of the root certificates, keyed by the SHA256 fingerprint of the
+/// certificate. Panics if the report can not be fetched or parsed, or if there are any duplicate
+/// fingerprints. See [`try_fetch_ccadb_roots`] for a fallible alternative.
+#[cfg(feature = "fetch")]
 pub async fn fetch_ccadb_roots() -> BTreeMap<String, CertificateMetadata> {
-    // Configure a Reqwest client that only trusts the CA certificate expected to be the
-    // root of trust for the CCADB server.
-    //
-    // If we see Unknown CA TLS validation failures from the Reqwest client in the future it
-    // likely indicates that the upstream service has changed certificate authorities. In this
-    // case the vendored root CA will need to be updated. You can find the current root in use with
-    // Chrome by:
-    //  1. Navigating to `https://ccadb-public.secure.force.com/mozilla/`
-    //  2. Clicking the lock icon.
-    //  3. Clicking "Connection is secure"
-    //  4. Clicking "Certificate is valid"
-    //  5. Clicking the "Details" tab.
-    //  6. Selecting the topmost "System Trust" entry.
-    //  7. Clicking "Export..." and saving the certificate to `webpki-roots/webpki-ccadb/src/data/`.
-    //  8. Committing the updated .pem root CA, and updating the `include_bytes!` path.
-    let root = include_bytes!("data/DigiCertGlobalRootCA.pem");
-    let root = reqwest::Certificate::from_pem(root).unwrap();
-    let client = reqwest::Client::builder()
-        .user_agent(format!("webpki-ccadb/v{}", env!("CARGO_PKG_VERSION")))
-        .add_root_certificate(root)
-        .build()
-        .unwrap();
-
-    let ccadb_url =
-        "https://ccadb.my.salesforce-sites.com/mozilla/IncludedCACertificateReportPEMCSV";
-    eprintln!("fetching {ccadb_url}...");
-
-    let req = client.get(ccadb_url).build().unwrap();
-    let csv_data = client
-        .execute(req)
+    try_fetch_ccadb_roots()
         .await
-        .expect("failed to fetch CSV")
-        .text()
-        .await
-        .unwrap();
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Fetch root certificate data from the CCADB server.
+///
+/// This uses the default [`CcadbClient`] configuration; use [`CcadbClient::builder()`] to customize
+/// the server, HTTP client or pinned root. Returns an ordered BTreeMap of the root certificates,
+/// keyed by the SHA256 fingerprint of the certificate, or an error if the report can not be
+/// fetched or parsed, or if there are any duplicate fingerprints.
+#[cfg(feature = "fetch")]
+pub async fn try_fetch_ccadb_roots() -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+    CcadbClient::builder().build()?.fetch_roots().await
+}
 
-    // Parse the CSV metadata.
-    let metadata = csv::ReaderBuilder::new()
+/// Fetch root certificate data from the CCADB server without an async runtime.
+///
+/// Requires the `blocking` cargo feature. Panics if the report can not be fetched or parsed, or
+/// if there are any duplicate fingerprints. See [`try_fetch_ccadb_roots_blocking`] for a fallible
+/// alternative.
+#[cfg(feature = "blocking")]
+pub fn fetch_ccadb_roots_blocking() -> BTreeMap<String, CertificateMetadata> {
+    try_fetch_ccadb_roots_blocking().unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Fetch root certificate data from the CCADB server without an async runtime.
+///
+/// Requires the `blocking` cargo feature. See [`try_fetch_ccadb_roots`] for details.
+#[cfg(feature = "blocking")]
+pub fn try_fetch_ccadb_roots_blocking() -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+    CcadbClient::builder().build_blocking()?.fetch_roots()
+}
+
+/// Parse root certificate data from CCADB CSV report content held in memory.
+///
+/// See [`parse_ccadb_csv`] for details.
+pub fn parse_ccadb_csv_str(csv_data: &str) -> BTreeMap<String, CertificateMetadata> {
+    parse_ccadb_csv(csv_data.as_bytes())
+}
+
+/// Parse root certificate data from a CCADB CSV report read from `reader`.
+///
+/// This performs the same parsing and filtering as `fetch_ccadb_roots` without touching
+/// the network, e.g. for use with a vendored copy of the report. Returns an ordered BTreeMap
+/// of the root certificates trusted for TLS, keyed by the SHA256 fingerprint of the certificate.
+/// Panics if the CSV can not be parsed, or if there are any duplicate fingerprints. See
+/// [`try_parse_ccadb_csv`] for a fallible alternative.
+pub fn parse_ccadb_csv(reader: impl Read) -> BTreeMap<String, CertificateMetadata> {
+    try_parse_ccadb_csv(reader).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Parse root certificate data from CCADB CSV report content held in memory.
+///
+/// See [`try_parse_ccadb_csv`] for details.
+pub fn try_parse_ccadb_csv_str(
+    csv_data: &str,
+) -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+    try_parse_ccadb_csv(csv_data.as_bytes())
+}
+
+/// Parse root certificate data from a CCADB CSV report read from `reader`.
+///
+/// Returns an ordered BTreeMap of the root certificates trusted for TLS under the default
+/// [`TrustPolicy`], keyed by the SHA256 fingerprint of the certificate, or an error if the CSV or
+/// any of the metadata used to decide trust can not be parsed, or if there are any duplicate
+/// fingerprints.
+pub fn try_parse_ccadb_csv(
+    reader: impl Read,
+) -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+    TrustPolicy::default().apply(read_ccadb_csv(reader)?)
+}
+
+/// Read every certificate from a CCADB CSV report read from `reader`, without any filtering.
+///
+/// Use [`TrustPolicy::apply`] to filter the result down to the trusted roots.
+pub fn read_ccadb_csv(reader: impl Read) -> Result<Vec<CertificateMetadata>, Error> {
+    Ok(csv::ReaderBuilder::new()
         .has_headers(true)
-        .from_reader(csv_data.as_bytes())
+        .from_reader(reader)
         .into_deserialize::<CertificateMetadata>()
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap();
-
-    // Filter for just roots we trust for TLS.
-    let trusted_tls_roots = metadata
-        .into_iter()
-        .filter(CertificateMetadata::trusted_for_tls)
-        .collect::<Vec<CertificateMetadata>>();
-
-    // Create an ordered BTreeMap of the roots, panicking for any duplicates.
-    let mut tls_roots_map = BTreeMap::new();
-    for root in trusted_tls_roots {
-        match tls_roots_map.get(&root.sha256_fingerprint) {
-            Some(_) => {
-                panic!("duplicate fingerprint {}", root.sha256_fingerprint);
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+/// An error encountered while fetching or processing CCADB data.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// The CCADB report could not be fetched.
+    #[cfg(feature = "fetch")]
+    Http(reqwest::Error),
+    /// The given CCADB server URL is not valid.
+    #[cfg(feature = "fetch")]
+    InvalidUrl(String),
+    /// The CCADB report could not be parsed as CSV.
+    Csv(csv::Error),
+    /// More than one certificate has the given SHA256 fingerprint.
+    DuplicateFingerprint(String),
+    /// The given trust bit is not known.
+    UnknownTrustBit(String),
+    /// The given trust bits combine `AllTrustBitsTurnedOff` with other trust bits.
+    ConflictingTrustBits(String),
+    /// The given distrust after date could not be parsed.
+    InvalidDistrustDate(String),
+    /// The PEM info did not contain a valid certificate.
+    InvalidPem(pki_types::pem::Error),
+    /// The given certificate serial number could not be parsed.
+    InvalidSerial(String),
+    /// The given SHA256 fingerprint could not be decoded.
+    InvalidFingerprint(String),
+    /// No root has the given SHA256 fingerprint.
+    UnknownFingerprint(String),
+    /// The certificate with the given SHA256 fingerprint could not be parsed.
+    InvalidCertificate(String),
+    /// The certificate does not match its given SHA256 fingerprint.
+    FingerprintMismatch(String),
+    /// A file could not be read or written.
+    Io(io::Error),
+    /// The snapshot manifest is invalid.
+    InvalidSnapshot(String),
+    /// The snapshot CSV report does not match the given SHA256 hash from its manifest.
+    SnapshotHashMismatch(String),
+    /// The name constraints are invalid or use unsupported syntax.
+    InvalidNameConstraints(String),
+    /// No root is trusted for the given purpose.
+    NoRoots(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "fetch")]
+            Self::Http(err) => write!(f, "failed to fetch CSV: {err}"),
+            #[cfg(feature = "fetch")]
+            Self::InvalidUrl(url) => write!(f, "invalid CCADB URL: {url:?}"),
+            Self::Csv(err) => write!(f, "failed to parse CSV: {err}"),
+            Self::DuplicateFingerprint(fp) => write!(f, "duplicate fingerprint {fp}"),
+            Self::UnknownTrustBit(bit) => write!(f, "unknown trust bit: {bit:?}"),
+            Self::ConflictingTrustBits(bits) => write!(
+                f,
+                "unexpected trust bits: AllTrustBitsTurnedOff is mutually exclusive (found {bits:?})"
+            ),
+            Self::InvalidDistrustDate(date) => write!(f, "invalid distrust after date: {date:?}"),
+            Self::InvalidPem(err) => write!(f, "invalid PEM: {err}"),
+            Self::InvalidSerial(serial) => {
+                write!(f, "invalid certificate serial number: {serial:?}")
+            }
+            Self::InvalidFingerprint(fp) => write!(f, "invalid sha256 fingerprint: {fp:?}"),
+            Self::UnknownFingerprint(fp) => write!(f, "no root with fingerprint {fp}"),
+            Self::InvalidCertificate(fp) => write!(f, "invalid certificate with fingerprint {fp}"),
+            Self::FingerprintMismatch(fp) => {
+                write!(f, "certificate does not match its fingerprint {fp}")
             }
-            None => {
-                tls_roots_map.insert(root.sha256_fingerprint.clone(), root);
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::InvalidSnapshot(reason) => write!(f, "invalid snapshot: {reason}"),
+            Self::SnapshotHashMismatch(hash) => {
+                write!(f, "snapshot CSV does not match its hash {hash}")
             }
+            Self::InvalidNameConstraints(reason) => {
+                write!(f, "invalid name constraints: {reason}")
+            }
+            Self::NoRoots(purpose) => write!(f, "no roots are trusted for {purpose}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "fetch")]
+            Self::Http(err) => Some(err),
+            Self::Csv(err) => Some(err),
+            Self::InvalidPem(err) => Some(err),
+            Self::Io(err) => Some(err),
+            _ => None,
         }
     }
+}
+
+#[cfg(feature = "fetch")]
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Http(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
 
-    tls_roots_map
+impl From<csv::Error> for Error {
+    fn from(err: csv::Error) -> Self {
+        Self::Csv(err)
+    }
 }
 
 #[non_exhaustive]
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Deserialize)]
 pub struct CertificateMetadata {
+    #[serde(rename = "Owner", default)]
+    pub owner: String,
+
     #[serde(rename = "Common Name or Certificate Name")]
     pub common_name_or_certificate_name: String,
 
@@ -97,6 +263,9 @@ pub struct CertificateMetadata {
     #[serde(rename = "Distrust for TLS After Date")]
     pub distrust_for_tls_after_date: String,
 
+    #[serde(rename = "Distrust for S/MIME After Date", default)]
+    pub distrust_for_smime_after_date: String,
+
     #[serde(rename = "Test Website - Revoked")]
     pub test_website_revoked: String,
 
@@ -108,132 +277,153 @@ pub struct CertificateMetadata {
 }
 
 impl CertificateMetadata {
-    /// Returns true if-and-only-if the issuer certificate should be considered trusted to issue TLS
-    /// certificates.
-    ///
-    /// In practice this means it must have valid TrustBits that include TrustBits::Websites,
-    /// and if the certificate has a distrust for TLS after date, that it's in the past or
-    /// within a 398-day grace period, and that the fingerprint isn't in the EXCLUDED_FINGERPRINTS
-    /// list.
-    ///
-    /// This grace period allows extant certificates issued before the distrust date to
-    /// remain valid for their lifetime. At the time of writing the CA/B forum baseline
-    /// reqs[0] peg this to 398 days (§ 6.3.2).
-    ///
-    /// [0]: <https://cabforum.org/working-groups/server/baseline-requirements/documents/CA-Browser-Forum-TLS-BR-2.0.9.pdf>
-    fn trusted_for_tls(&self) -> bool {
-        // If the fingerprint is in the excluded list, it's not trusted based on policy
-        // we're imposing ourselves.
-        if EXCLUDED_FINGERPRINTS.contains(&self.sha256_fingerprint.as_str()) {
-            return false;
-        }
-
-        let has_tls_trust_bit = self.trust_bits().contains(&TrustBits::Websites);
-
-        match (has_tls_trust_bit, self.tls_distrust_after()) {
-            // No website trust bit - not trusted for tls.
-            (false, _) => false,
-            // Has website trust bit, no distrust after - trusted for tls.
-            (true, None) => true,
-            // Trust bit, populated distrust after - check if we're within the grace period.
-            (true, Some(distrust_after)) => {
-                Utc::now().naive_utc() < distrust_after.add(Duration::days(398)).into()
-            }
-        }
+    /// Parse every field of the metadata into a [`ParsedRoot`], returning an error if any of
+    /// them are invalid.
+    pub fn parse(&self) -> Result<ParsedRoot, Error> {
+        ParsedRoot::try_from(self)
     }
 
     /// Return the Mozilla applied constraints for the certificate (if any). The constraints
     /// will be encoded in the DER form expected by the webpki crate's TrustAnchor representation.
+    /// Panics if the constraints can not be parsed. See `name_constraints` for a fallible
+    /// alternative.
     pub fn mozilla_applied_constraints(&self) -> Option<Vec<u8>> {
-        if self.mozilla_applied_constraints.is_empty() {
-            return None;
+        self.name_constraints()
+            .expect("invalid Mozilla applied constraints")
+            .map(|constraints| constraints.to_der())
+    }
+
+    /// Parse the Mozilla applied constraints for the certificate (if any), or return an error if
+    /// they use syntax that is not understood.
+    ///
+    /// The CCADB report lists permitted DNS names, e.g. `*.tr`. This also accepts the syntax of
+    /// [`NameConstraints::from_str()`], which is this crate's own: comma separated subtrees,
+    /// each optionally prefixed by `permitted;` or `excluded;`, and written as `DNS:.tr`,
+    /// `IP:192.0.2.0/24` or `dirName:C=TR/O=Example`.
+    pub fn name_constraints(&self) -> Result<Option<NameConstraints>, Error> {
+        match self.mozilla_applied_constraints.trim() {
+            "" => Ok(None),
+            constraints => constraints.parse().map(Some),
+        }
+    }
+
+    /// Returns true if-and-only-if the certificate should be considered trusted for the purpose
+    /// described by `bit`, for certificates issued on date `at`.
+    ///
+    /// In practice this means it must have `bit` in its trust bits, and if it has a distrust
+    /// after date for that purpose, `at` must not be after it. Unlike `TrustPolicy`, no grace
+    /// period is applied and no fingerprints are excluded. Returns an error if the trust bits or
+    /// distrust after date can not be parsed.
+    pub fn trusted_for(&self, bit: TrustBits, at: NaiveDate) -> Result<bool, Error> {
+        if bit == TrustBits::AllTrustBitsTurnedOff || !self.trust_bits()?.contains(&bit) {
+            return Ok(false);
         }
 
-        // NOTE: To date there's only one CA with a applied constraints value, and it has only one
-        // permitted subtree constraint imposed. It's not clear how multiple constraints would be
-        // expressed. This method makes a best guess but may need to be revisited in the future.
-        // https://groups.google.com/a/ccadb.org/g/public/c/TlDivISPVT4/m/jbWGuM4YAgAJ
-        let included_subtrees = self.mozilla_applied_constraints.split(',');
-
-        // Important: the webpki representation of name constraints elides:
-        //   - the outer BITSTRING of the X.509 extension value.
-        //   - the outer NameConstraints SEQUENCE over the permitted/excluded subtrees.
-        //
-        // See https://www.rfc-editor.org/rfc/rfc5280#section-4.2.1.10
-        let der = yasna::construct_der(|writer| {
-            // permittedSubtrees [0]
-            writer.write_tagged_implicit(yasna::Tag::context(0), |writer| {
-                // GeneralSubtrees
-                writer.write_sequence(|writer| {
-                    for included_subtree in included_subtrees {
-                        // base GeneralName
-                        writer.next().write_sequence(|writer| {
-                            writer
-                                .next()
-                                // DnsName
-                                .write_tagged_implicit(yasna::Tag::context(2), |writer| {
-                                    writer
-                                        .write_ia5_string(included_subtree.trim_start_matches('*'))
-                                })
-                        })
-                        // minimum [0] (absent, 0 default)
-                        // maximum [1] (must be omitted).
-                    }
-                })
-            })
-        });
-
-        Some(der)
+        Ok(match self.distrust_after(bit)? {
+            Some(distrust_after) => at <= distrust_after,
+            None => true,
+        })
+    }
+
+    /// Return the NaiveDate after which this certificate should not be trusted for the purpose
+    /// described by `bit` (if any). Returns an error if there is a distrust after date value
+    /// that can not be parsed.
+    pub(crate) fn distrust_after(&self, bit: TrustBits) -> Result<Option<NaiveDate>, Error> {
+        match bit {
+            TrustBits::Websites => self.tls_distrust_after(),
+            TrustBits::Email => self.smime_distrust_after(),
+            TrustBits::Code | TrustBits::AllTrustBitsTurnedOff => Ok(None),
+        }
     }
 
     /// Return the NaiveDate after which this certificate should not be trusted for TLS (if any).
-    /// Panics if there is a distrust for TLS after date value that can not be parsed.
-    fn tls_distrust_after(&self) -> Option<NaiveDate> {
+    /// Returns an error if there is a distrust for TLS after date value that can not be parsed.
+    pub fn tls_distrust_after(&self) -> Result<Option<NaiveDate>, Error> {
         match &self.distrust_for_tls_after_date {
-            date if date.is_empty() => None,
-            date => Some(
-                NaiveDate::parse_from_str(date, "%Y.%m.%d")
-                    .unwrap_or_else(|_| panic!("invalid distrust for tls after date: {date:?}")),
-            ),
+            date if date.is_empty() => Ok(None),
+            date => NaiveDate::parse_from_str(date, "%Y.%m.%d")
+                .map(Some)
+                .map_err(|_| Error::InvalidDistrustDate(date.clone())),
+        }
+    }
+
+    /// Return the NaiveDate after which this certificate should not be trusted for S/MIME (if
+    /// any). Returns an error if there is a distrust for S/MIME after date value that can not be
+    /// parsed.
+    pub fn smime_distrust_after(&self) -> Result<Option<NaiveDate>, Error> {
+        match &self.distrust_for_smime_after_date {
+            date if date.is_empty() => Ok(None),
+            date => NaiveDate::parse_from_str(date, "%Y.%m.%d")
+                .map(Some)
+                .map_err(|_| Error::InvalidDistrustDate(date.clone())),
         }
     }
 
     /// Returns the DER encoding of the certificate contained in the metadata PEM. Panics if
-    /// there is an error, or no certificate in the PEM content.
+    /// there is an error, or no certificate in the PEM content. See `try_der` for a fallible
+    /// alternative.
     pub fn der(&self) -> CertificateDer<'static> {
-        CertificateDer::from_pem_slice(self.pem().as_bytes()).expect("invalid PEM")
+        self.try_der().expect("invalid PEM")
+    }
+
+    /// Returns the DER encoding of the certificate contained in the metadata PEM, or an error
+    /// if there is no valid certificate in the PEM content.
+    pub fn try_der(&self) -> Result<CertificateDer<'static>, Error> {
+        CertificateDer::from_pem_slice(self.pem().as_bytes()).map_err(Error::InvalidPem)
+    }
+
+    /// Returns the `notAfter` time of the certificate contained in the metadata PEM, or an error
+    /// if there is no valid certificate in the PEM content.
+    pub fn not_after(&self) -> Result<DateTime<Utc>, Error> {
+        let der = self.try_der()?;
+        let (_, cert) = x509_parser::parse_x509_certificate(&der)
+            .map_err(|_| Error::InvalidCertificate(self.sha256_fingerprint.clone()))?;
+        DateTime::from_timestamp(cert.validity().not_after.timestamp(), 0)
+            .ok_or_else(|| Error::InvalidCertificate(self.sha256_fingerprint.clone()))
     }
 
     /// Returns the serial number for the certificate. Panics if the certificate serial number
-    /// from the metadata can not be parsed as a base 16 unsigned big integer.
+    /// from the metadata can not be parsed as a base 16 unsigned big integer. See `try_serial`
+    /// for a fallible alternative.
     pub fn serial(&self) -> BigUint {
-        BigUint::parse_bytes(self.certificate_serial_number.as_bytes(), 16)
+        self.try_serial()
             .expect("invalid certificate serial number")
     }
 
+    /// Returns the serial number for the certificate, or an error if the certificate serial
+    /// number from the metadata can not be parsed as a base 16 unsigned big integer.
+    pub fn try_serial(&self) -> Result<BigUint, Error> {
+        BigUint::parse_bytes(self.certificate_serial_number.as_bytes(), 16)
+            .ok_or_else(|| Error::InvalidSerial(self.certificate_serial_number.clone()))
+    }
+
     /// Returns the colon separated string with the metadata SHA256 fingerprint for the
     /// certificate. Panics if the sha256 fingerprint from the metadata can't be decoded.
+    /// See `try_sha256_fp` for a fallible alternative.
     pub fn sha256_fp(&self) -> String {
-        x509_parser::utils::format_serial(
-            &hex::decode(&self.sha256_fingerprint).expect("invalid sha256 fingerprint"),
-        )
+        self.try_sha256_fp().expect("invalid sha256 fingerprint")
+    }
+
+    /// Returns the colon separated string with the metadata SHA256 fingerprint for the
+    /// certificate, or an error if the sha256 fingerprint from the metadata can't be decoded.
+    pub fn try_sha256_fp(&self) -> Result<String, Error> {
+        let fp = hex::decode(&self.sha256_fingerprint)
+            .map_err(|_| Error::InvalidFingerprint(self.sha256_fingerprint.clone()))?;
+        Ok(x509_parser::utils::format_serial(&fp))
     }
 
-    /// Returns the set of trust bits expressed for this certificate. Panics if the raw
-    /// trust bits are invalid/unknown.
-    fn trust_bits(&self) -> HashSet<TrustBits> {
+    /// Returns the set of trust bits expressed for this certificate. Returns an error if the
+    /// raw trust bits are invalid/unknown.
+    pub fn trust_bits(&self) -> Result<HashSet<TrustBits>, Error> {
         let bits = self
             .trust_bits
             .split(';')
-            .map(TrustBits::from)
-            .collect::<HashSet<_>>();
+            .map(str::parse)
+            .collect::<Result<HashSet<TrustBits>, _>>()?;
         if bits.contains(&TrustBits::AllTrustBitsTurnedOff) && bits.len() > 1 {
-            panic!(
-                "unexpected trust bits: AllTrustBitsTurnedOff \
-                   is mutually exclusive (found {bits:?})"
-            );
+            return Err(Error::ConflictingTrustBits(self.trust_bits.clone()));
         }
-        bits
+        Ok(bits)
     }
 
     /// Returns the PEM metadata for the certificate with the leading/trailing single quotes
@@ -255,7 +445,7 @@ impl Ord for CertificateMetadata {
     }
 }
 
-#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
 #[non_exhaustive]
 /// TrustBits describe the possible Mozilla root certificate trust bits.
 pub enum TrustBits {
@@ -271,16 +461,25 @@ pub enum TrustBits {
 
 impl From<&str> for TrustBits {
     fn from(value: &str) -> Self {
+        value.parse().unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl FromStr for TrustBits {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
-            "Websites" => TrustBits::Websites,
-            "Email" => TrustBits::Email,
-            "Code" => TrustBits::Code,
-            "All Trust Bits Turned Off" => TrustBits::AllTrustBitsTurnedOff,
-            val => panic!("unknown trust bit: {val:?}"),
+            "Websites" => Ok(TrustBits::Websites),
+            "Email" => Ok(TrustBits::Email),
+            "Code" => Ok(TrustBits::Code),
+            "All Trust Bits Turned Off" => Ok(TrustBits::AllTrustBitsTurnedOff),
+            val => Err(Error::UnknownTrustBit(val.to_string())),
         }
     }
 }
 
+/// The roots excluded from the roots trusted for TLS, by [`TrustPolicy::default()`].
 static EXCLUDED_FINGERPRINTS: &[&str] = &[
     // CN=GLOBALTRUST 2020 O=e-commerce monitoring GmbH
     // This CA is being distrusted by the Mozilla root program for TLS certificates issued after 2024.06.30.
@@ -289,56 +488,233 @@ static EXCLUDED_FINGERPRINTS: &[&str] = &[
     "9A296A5182D1D451A2E37F439B74DAAFA267523329F90F9A0D2007C334E23C9A",
 ];
 
+/// The roots excluded from the roots trusted for email protection, by
+/// [`TrustPolicy::email_protection()`].
+///
+/// The TLS exclusions above are about TLS certificates, so they do not apply here.
+static EMAIL_EXCLUDED_FINGERPRINTS: &[&str] = &[];
+
+/// Returns the metadata of a root trusted for Websites, with every other field empty, for tests
+/// to override.
+#[cfg(test)]
+pub(crate) fn test_metadata() -> CertificateMetadata {
+    CertificateMetadata {
+        owner: "Test Owner".to_string(),
+        common_name_or_certificate_name: "Test".to_string(),
+        certificate_serial_number: "1".to_string(),
+        sha256_fingerprint: "1".to_string(),
+        trust_bits: "Websites".to_string(),
+        distrust_for_tls_after_date: String::new(),
+        distrust_for_smime_after_date: String::new(),
+        test_website_revoked: String::new(),
+        mozilla_applied_constraints: String::new(),
+        pem_info: String::new(),
+    }
+}
+
+/// A report of a single real root, "DigiCert Global Root CA", trusted for Websites and Email
+/// with no distrust dates or constraints, for tests to modify.
+#[cfg(test)]
+pub(crate) const TEST_ROOT_CSV: &str = concat!(
+    "Owner,Common Name or Certificate Name,Certificate Serial Number,SHA-256 Fingerprint,",
+    "Trust Bits,Distrust for TLS After Date,Test Website - Revoked,",
+    "Mozilla Applied Constraints,PEM Info\n",
+    "DigiCert,DigiCert Global Root CA,083BE056904246B1A1756AC95991C74A,",
+    "4348A0E9444C78CB265E058D5E8944B4D84F9662BD26DB257F8934A443C70161,",
+    "Websites;Email,,,,\"'",
+    include_str!("data/DigiCertGlobalRootCA.pem"),
+    "'\"\n",
+);
+
 #[cfg(test)]
 mod tests {
+    use std::ops::Add;
+
+    use chrono::{Duration, Utc};
+
     use super::*;
 
     #[test]
     fn test_trusted_for_tls() {
-        let mut metadata = CertificateMetadata {
-            common_name_or_certificate_name: "Test".to_string(),
-            certificate_serial_number: "1".to_string(),
-            sha256_fingerprint: "1".to_string(),
-            trust_bits: "Websites".to_string(),
-            distrust_for_tls_after_date: "".to_string(),
-            test_website_revoked: "".to_string(),
-            mozilla_applied_constraints: "".to_string(),
-            pem_info: "".to_string(),
-        };
+        let mut metadata = test_metadata();
         // Trust bit set for Websites, no distrust date.
-        assert!(metadata.trusted_for_tls());
+        assert!(trusted_for_tls(&metadata));
 
         // Trust bit _not_ set for Websites.
         metadata.trust_bits = "Email".to_string();
-        assert!(!metadata.trusted_for_tls());
+        assert!(!trusted_for_tls(&metadata));
 
         // Trust bit set for Websites, no distrut date.
         metadata.trust_bits = "Websites;Email".to_string();
-        assert!(metadata.trusted_for_tls());
+        assert!(trusted_for_tls(&metadata));
 
         // Trust bit set for Websites, distrust date far in the past.
         metadata.trust_bits = "Websites".to_string();
         metadata.distrust_for_tls_after_date = "2000.01.01".to_string();
-        assert!(!metadata.trusted_for_tls());
+        assert!(!trusted_for_tls(&metadata));
 
         // Trust bit set for Websites, distrust date in the future.
         let now = Utc::now().naive_utc();
         let future_distrust = now.add(Duration::days(365 * 5));
         metadata.distrust_for_tls_after_date = future_distrust.format("%Y.%m.%d").to_string();
-        assert!(metadata.trusted_for_tls());
+        assert!(trusted_for_tls(&metadata));
 
         // Trust bit set for Websites, distrust date has passed, but within grace period.
         let past_distrust = now.add(Duration::days(-397));
         metadata.distrust_for_tls_after_date = past_distrust.format("%Y.%m.%d").to_string();
-        assert!(metadata.trusted_for_tls());
+        assert!(trusted_for_tls(&metadata));
 
         // Trust bit set for Websites, distrust date has passed, outside grace period.
         let past_distrust = now.add(Duration::days(-398));
         metadata.distrust_for_tls_after_date = past_distrust.format("%Y.%m.%d").to_string();
-        assert!(!metadata.trusted_for_tls());
+        assert!(!trusted_for_tls(&metadata));
 
         // Certificate FP is excluded.
         metadata.sha256_fingerprint = EXCLUDED_FINGERPRINTS[0].to_string();
-        assert!(!metadata.trusted_for_tls());
+        assert!(!trusted_for_tls(&metadata));
+    }
+
+    fn trusted_for_tls(metadata: &CertificateMetadata) -> bool {
+        TrustPolicy::default().is_trusted(metadata).unwrap()
+    }
+
+    #[test]
+    fn test_trusted_for() {
+        let mut metadata = CertificateMetadata {
+            trust_bits: "Websites;Email".to_string(),
+            distrust_for_tls_after_date: "2024.06.30".to_string(),
+            distrust_for_smime_after_date: "2025.01.31".to_string(),
+            ..test_metadata()
+        };
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            metadata.trust_bits().unwrap(),
+            HashSet::from([TrustBits::Websites, TrustBits::Email])
+        );
+        assert_eq!(
+            metadata.tls_distrust_after().unwrap(),
+            Some(date(2024, 6, 30))
+        );
+
+        // The TLS distrust date applies to Websites trust, with no grace period.
+        assert!(metadata
+            .trusted_for(TrustBits::Websites, date(2024, 6, 30))
+            .unwrap());
+        assert!(!metadata
+            .trusted_for(TrustBits::Websites, date(2024, 7, 1))
+            .unwrap());
+
+        // The S/MIME distrust date applies to Email trust.
+        assert!(metadata
+            .trusted_for(TrustBits::Email, date(2025, 1, 31))
+            .unwrap());
+        assert!(!metadata
+            .trusted_for(TrustBits::Email, date(2025, 2, 1))
+            .unwrap());
+
+        // Neither applies to other trust bits.
+        assert!(!metadata
+            .trusted_for(TrustBits::Code, date(2020, 1, 1))
+            .unwrap());
+        assert!(!metadata
+            .trusted_for(TrustBits::AllTrustBitsTurnedOff, date(2020, 1, 1))
+            .unwrap());
+
+        metadata.trust_bits = "All Trust Bits Turned Off".to_string();
+        assert!(!metadata
+            .trusted_for(TrustBits::Websites, date(2020, 1, 1))
+            .unwrap());
+
+        metadata.distrust_for_tls_after_date = "30/06/2024".to_string();
+        assert!(matches!(
+            metadata.tls_distrust_after(),
+            Err(Error::InvalidDistrustDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_ccadb_csv() {
+        let roots = parse_ccadb_csv_str(TEST_CSV);
+        assert_eq!(roots.keys().collect::<Vec<_>>(), vec!["AA", "CC"]);
+        assert_eq!(roots["AA"].common_name_or_certificate_name, "Websites Root");
+        assert_eq!(
+            roots["CC"].common_name_or_certificate_name,
+            "Websites and Email Root"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate fingerprint AA")]
+    fn test_parse_ccadb_csv_duplicate() {
+        let mut csv_data = TEST_CSV.to_string();
+        csv_data.push_str("Websites Root Again,01,AA,Websites,,,,''\n");
+        parse_ccadb_csv(csv_data.as_bytes());
     }
+
+    #[test]
+    fn test_try_parse_ccadb_csv_errors() {
+        let mut csv_data = TEST_CSV.to_string();
+        csv_data.push_str("Websites Root Again,01,AA,Websites,,,,''\n");
+        assert!(matches!(
+            try_parse_ccadb_csv_str(&csv_data),
+            Err(Error::DuplicateFingerprint(fp)) if fp == "AA"
+        ));
+
+        let mut csv_data = TEST_CSV.to_string();
+        csv_data.push_str("Unknown Bits Root,05,EE,Websites;Carrier Pigeons,,,,''\n");
+        assert!(matches!(
+            try_parse_ccadb_csv_str(&csv_data),
+            Err(Error::UnknownTrustBit(bit)) if bit == "Carrier Pigeons"
+        ));
+
+        let mut csv_data = TEST_CSV.to_string();
+        csv_data.push_str("Conflicting Bits Root,05,EE,Websites;All Trust Bits Turned Off,,,,''\n");
+        assert!(matches!(
+            try_parse_ccadb_csv_str(&csv_data),
+            Err(Error::ConflictingTrustBits(_))
+        ));
+
+        let mut csv_data = TEST_CSV.to_string();
+        csv_data.push_str("Bad Date Root,05,EE,Websites,2000-01-01,,,''\n");
+        assert!(matches!(
+            try_parse_ccadb_csv_str(&csv_data),
+            Err(Error::InvalidDistrustDate(date)) if date == "2000-01-01"
+        ));
+
+        assert!(matches!(
+            try_parse_ccadb_csv_str("not,a\nccadb,report\n"),
+            Err(Error::Csv(_))
+        ));
+    }
+
+    #[test]
+    fn test_try_metadata_accessors() {
+        let roots = parse_ccadb_csv_str(TEST_CSV);
+        let mut metadata = roots["AA"].clone();
+        assert_eq!(metadata.try_serial().unwrap(), BigUint::from(1u8));
+        assert_eq!(metadata.try_sha256_fp().unwrap(), "aa");
+        assert!(matches!(metadata.try_der(), Err(Error::InvalidPem(_))));
+
+        metadata.certificate_serial_number = "not hex".to_string();
+        assert!(matches!(
+            metadata.try_serial(),
+            Err(Error::InvalidSerial(_))
+        ));
+
+        metadata.sha256_fingerprint = "not hex".to_string();
+        assert!(matches!(
+            metadata.try_sha256_fp(),
+            Err(Error::InvalidFingerprint(_))
+        ));
+    }
+
+    const TEST_CSV: &str = "\
+Common Name or Certificate Name,Certificate Serial Number,SHA-256 Fingerprint,Trust Bits,\
+Distrust for TLS After Date,Test Website - Revoked,Mozilla Applied Constraints,PEM Info
+Websites Root,01,AA,Websites,,,,''
+Email Root,02,BB,Email,,,,''
+Websites and Email Root,03,CC,Websites;Email,,,,''
+Distrusted Root,04,DD,Websites,2000.01.01,,,''
+";
 }
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use num_bigint::BigUint;
+use pki_types::CertificateDer;
+use serde::Deserialize;
+
+use crate::{CertificateMetadata, Error, NameConstraints, TrustBits};
+
+/// A validated view of [`CertificateMetadata`], with each field parsed into a typed value.
+///
+/// This can be built from [`CertificateMetadata`] with a single fallible conversion (see
+/// [`CertificateMetadata::parse()`]), or deserialized directly from a CCADB CSV report.
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+#[serde(try_from = "CertificateMetadata")]
+pub struct ParsedRoot {
+    /// The common name or certificate name label of the certificate.
+    pub label: String,
+
+    /// The serial number of the certificate.
+    pub serial: BigUint,
+
+    /// The SHA256 fingerprint of the certificate.
+    pub sha256_fingerprint: [u8; 32],
+
+    /// The Mozilla trust bits of the certificate.
+    pub trust_bits: HashSet<TrustBits>,
+
+    /// The date after which the certificate should not be trusted for TLS (if any).
+    pub tls_distrust_after: Option<NaiveDate>,
+
+    /// The date after which the certificate should not be trusted for S/MIME (if any).
+    pub smime_distrust_after: Option<NaiveDate>,
+
+    /// The Mozilla applied name constraints of the certificate (if any). Use
+    /// [`NameConstraints::to_der()`] for the DER form expected by the webpki crate's TrustAnchor
+    /// representation.
+    pub name_constraints: Option<NameConstraints>,
+
+    /// The DER encoding of the certificate.
+    pub der: CertificateDer<'static>,
+}
+
+impl TryFrom<&CertificateMetadata> for ParsedRoot {
+    type Error = Error;
+
+    fn try_from(metadata: &CertificateMetadata) -> Result<Self, Self::Error> {
+        let sha256_fingerprint = hex::decode(&metadata.sha256_fingerprint)
+            .ok()
+            .and_then(|fp| <[u8; 32]>::try_from(fp).ok())
+            .ok_or_else(|| Error::InvalidFingerprint(metadata.sha256_fingerprint.clone()))?;
+
+        Ok(Self {
+            label: metadata.common_name_or_certificate_name.clone(),
+            serial: metadata.try_serial()?,
+            sha256_fingerprint,
+            trust_bits: metadata.trust_bits()?,
+            tls_distrust_after: metadata.tls_distrust_after()?,
+            smime_distrust_after: metadata.smime_distrust_after()?,
+            name_constraints: metadata.name_constraints()?,
+            der: metadata.try_der()?,
+        })
+    }
+}
+
+impl TryFrom<CertificateMetadata> for ParsedRoot {
+    type Error = Error;
+
+    fn try_from(metadata: CertificateMetadata) -> Result<Self, Self::Error> {
+        Self::try_from(&metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_ROOT_CSV;
+
+    #[test]
+    fn test_parsed_root() {
+        let roots = csv::Reader::from_reader(test_csv().as_bytes())
+            .into_deserialize::<ParsedRoot>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(roots.len(), 1);
+
+        let root = &roots[0];
+        assert_eq!(root.label, "DigiCert Global Root CA");
+        assert_eq!(
+            root.serial,
+            BigUint::parse_bytes(b"083BE056904246B1A1756AC95991C74A", 16).unwrap()
+        );
+        assert_eq!(
+            hex::encode_upper(root.sha256_fingerprint),
+            "4348A0E9444C78CB265E058D5E8944B4D84F9662BD26DB257F8934A443C70161"
+        );
+        assert_eq!(
+            root.trust_bits,
+            HashSet::from([TrustBits::Websites, TrustBits::Email])
+        );
+        assert_eq!(
+            root.tls_distrust_after,
+            Some(NaiveDate::from_ymd_opt(2040, 1, 2).unwrap())
+        );
+        assert_eq!(root.smime_distrust_after, None);
+        assert_eq!(
+            root.name_constraints,
+            Some("*.example".parse::<NameConstraints>().unwrap())
+        );
+        assert_eq!(root.der, roots_metadata()[0].der());
+    }
+
+    #[test]
+    fn test_parsed_root_errors() {
+        let mut metadata = roots_metadata().remove(0);
+        assert!(metadata.parse().is_ok());
+
+        metadata.sha256_fingerprint = "AABB".to_string();
+        assert!(matches!(
+            metadata.parse(),
+            Err(Error::InvalidFingerprint(fp)) if fp == "AABB"
+        ));
+
+        let mut metadata = roots_metadata().remove(0);
+        metadata.trust_bits = "Websites;Unknown".to_string();
+        assert!(matches!(metadata.parse(), Err(Error::UnknownTrustBit(_))));
+
+        let mut metadata = roots_metadata().remove(0);
+        metadata.pem_info = "''".to_string();
+        assert!(matches!(metadata.parse(), Err(Error::InvalidPem(_))));
+
+        // Deserializing surfaces the conversion error.
+        let csv_data = test_csv().replace("2040.01.02", "2040-01-02");
+        let err = csv::Reader::from_reader(csv_data.as_bytes())
+            .into_deserialize::<ParsedRoot>()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid distrust after date"));
+    }
+
+    fn roots_metadata() -> Vec<CertificateMetadata> {
+        crate::read_ccadb_csv(test_csv().as_bytes()).unwrap()
+    }
+
+    /// The test root, distrusted for TLS after 2040-01-02 and constrained to `*.example`.
+    fn test_csv() -> String {
+        TEST_ROOT_CSV.replace(
+            "Websites;Email,,,,",
+            "Websites;Email,2040.01.02,,*.example,",
+        )
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{Duration, NaiveDate, Utc};
+
+use crate::{
+    CertificateMetadata, Error, TrustBits, EMAIL_EXCLUDED_FINGERPRINTS, EXCLUDED_FINGERPRINTS,
+};
+
+/// A policy deciding which CCADB root certificates are included in a generated root bundle.
+///
+/// The default policy matches the one used to generate `webpki-roots`: certificates must have
+/// the `Websites` trust bit, must not be past their distrust for TLS after date by more than
+/// [`DEFAULT_GRACE_PERIOD_DAYS`] at the time of evaluation, and must not be excluded by
+/// fingerprint.
+#[derive(Debug, Clone)]
+pub struct TrustPolicy {
+    grace_period: Duration,
+    excluded: BTreeSet<String>,
+    included: BTreeSet<String>,
+    required_trust_bits: BTreeSet<TrustBits>,
+    at: Option<NaiveDate>,
+}
+
+impl TrustPolicy {
+    /// Returns the policy used to select roots trusted for email protection (S/MIME).
+    ///
+    /// This is like the default policy, but requires the `Email` trust bit, applies the distrust
+    /// for S/MIME after date with a grace period of [`SMIME_GRACE_PERIOD_DAYS`], and has its own
+    /// default exclusions rather than those for TLS.
+    pub fn email_protection() -> Self {
+        Self::new(
+            TrustBits::Email,
+            SMIME_GRACE_PERIOD_DAYS,
+            EMAIL_EXCLUDED_FINGERPRINTS,
+        )
+    }
+
+    fn new(trust_bit: TrustBits, grace_period_days: i64, excluded: &[&str]) -> Self {
+        Self {
+            grace_period: Duration::days(grace_period_days),
+            excluded: excluded
+                .iter()
+                .map(|fp| normalize_fingerprint(fp))
+                .collect(),
+            included: BTreeSet::new(),
+            required_trust_bits: BTreeSet::from([trust_bit]),
+            at: None,
+        }
+    }
+
+    /// Set the grace period after a distrust after date during which the certificate is
+    /// still considered trusted.
+    ///
+    /// This grace period allows extant certificates issued before the distrust date to
+    /// remain valid for their lifetime. Defaults to [`DEFAULT_GRACE_PERIOD_DAYS`].
+    pub fn grace_period(mut self, grace_period: Duration) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+    /// Exclude the certificate with the given hex encoded SHA256 fingerprint, in addition to
+    /// those excluded by default.
+    pub fn exclude(mut self, sha256_fingerprint: &str) -> Self {
+        self.excluded
+            .insert(normalize_fingerprint(sha256_fingerprint));
+        self
+    }
+
+    /// Include the certificate with the given hex encoded SHA256 fingerprint regardless of its
+    /// trust bits, distrust after date, or any exclusion.
+    pub fn include(mut self, sha256_fingerprint: &str) -> Self {
+        self.included
+            .insert(normalize_fingerprint(sha256_fingerprint));
+        self
+    }
+
+    /// Set the trust bits a certificate must have (all of) to be included.
+    ///
+    /// Defaults to just [`TrustBits::Websites`].
+    pub fn required_trust_bits(mut self, bits: impl IntoIterator<Item = TrustBits>) -> Self {
+        self.required_trust_bits = bits.into_iter().collect();
+        self
+    }
+
+    /// Evaluate distrust after dates as of the given date, rather than the current date.
+    pub fn at(mut self, date: NaiveDate) -> Self {
+        self.at = Some(date);
+        self
+    }
+
+    /// Returns true if-and-only-if the certificate should be included under this policy.
+    ///
+    /// Returns an error if the trust bits or distrust after date can not be parsed.
+    pub fn is_trusted(&self, metadata: &CertificateMetadata) -> Result<bool, Error> {
+        let fingerprint = normalize_fingerprint(&metadata.sha256_fingerprint);
+        if self.included.contains(&fingerprint) {
+            return Ok(true);
+        }
+
+        // If the fingerprint is in the excluded list, it's not trusted based on policy
+        // we're imposing ourselves.
+        if self.excluded.contains(&fingerprint) {
+            return Ok(false);
+        }
+
+        let trust_bits = metadata.trust_bits()?;
+        if !self
+            .required_trust_bits
+            .iter()
+            .all(|bit| trust_bits.contains(bit))
+        {
+            return Ok(false);
+        }
+
+        // For each required purpose with a distrust after date, check if we're within the
+        // grace period.
+        let at = self.at.unwrap_or_else(|| Utc::now().date_naive());
+        for bit in &self.required_trust_bits {
+            if let Some(distrust_after) = metadata.distrust_after(*bit)? {
+                if at >= distrust_after + self.grace_period {
+                    return Ok(false);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Filter the given certificates down to those trusted under this policy.
+    ///
+    /// Returns an ordered BTreeMap of the trusted certificates, keyed by SHA256 fingerprint, or
+    /// an error if any certificate can not be evaluated or if there are any duplicate
+    /// fingerprints.
+    pub fn apply(
+        &self,
+        metadata: impl IntoIterator<Item = CertificateMetadata>,
+    ) -> Result<BTreeMap<String, CertificateMetadata>, Error> {
+        let mut roots = BTreeMap::new();
+        for root in metadata {
+            if !self.is_trusted(&root)? {
+                continue;
+            }
+
+            match roots.get(&root.sha256_fingerprint) {
+                Some(_) => {
+                    return Err(Error::DuplicateFingerprint(root.sha256_fingerprint));
+                }
+                None => {
+                    roots.insert(root.sha256_fingerprint.clone(), root);
+                }
+            }
+        }
+
+        Ok(roots)
+    }
+}
+
+impl Default for TrustPolicy {
+    fn default() -> Self {
+        Self::new(
+            TrustBits::Websites,
+            DEFAULT_GRACE_PERIOD_DAYS,
+            EXCLUDED_FINGERPRINTS,
+        )
+    }
+}
+
+/// The default grace period (in days) after a distrust after date.
+///
+/// At the time of writing the CA/B forum baseline reqs[0] peg the maximum TLS certificate
+/// validity period to 398 days (§ 6.3.2).
+///
+/// [0]: <https://cabforum.org/working-groups/server/baseline-requirements/documents/CA-Browser-Forum-TLS-BR-2.0.9.pdf>
+pub const DEFAULT_GRACE_PERIOD_DAYS: i64 = 398;
+
+/// The grace period (in days) after a distrust for S/MIME after date used by
+/// [`TrustPolicy::email_protection()`].
+///
+/// At the time of writing the CA/B forum S/MIME baseline reqs[0] peg the maximum S/MIME
+/// certificate validity period to 825 days (§ 6.3.2).
+///
+/// [0]: <https://cabforum.org/working-groups/smime/requirements/>
+pub const SMIME_GRACE_PERIOD_DAYS: i64 = 825;
+
+fn normalize_fingerprint(fp: &str) -> String {
+    fp.chars()
+        .filter(|c| *c != ':')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_metadata;
+
+    #[test]
+    fn test_policy() {
+        let mut metadata = CertificateMetadata {
+            sha256_fingerprint: "AA".to_string(),
+            trust_bits: "Websites;Email".to_string(),
+            distrust_for_tls_after_date: "2020.01.01".to_string(),
+            ..test_metadata()
+        };
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        // Evaluated at an explicit date, within and outside the default grace period.
+        let policy = TrustPolicy::default().at(date("2021-02-01"));
+        assert!(policy.is_trusted(&metadata).unwrap());
+        let policy = TrustPolicy::default().at(date("2021-02-02"));
+        assert!(!policy.is_trusted(&metadata).unwrap());
+
+        // With a custom grace period.
+        let policy = TrustPolicy::default()
+            .at(date("2020-06-01"))
+            .grace_period(Duration::days(30));
+        assert!(!policy.is_trusted(&metadata).unwrap());
+
+        // With different required trust bits the TLS distrust date doesn't apply.
+        let policy = TrustPolicy::default()
+            .at(date("2030-01-01"))
+            .required_trust_bits([TrustBits::Email]);
+        assert!(policy.is_trusted(&metadata).unwrap());
+        let policy = TrustPolicy::default()
+            .at(date("2030-01-01"))
+            .required_trust_bits([TrustBits::Code]);
+        assert!(!policy.is_trusted(&metadata).unwrap());
+
+        // The email protection policy applies the S/MIME distrust date instead.
+        metadata.distrust_for_smime_after_date = "2020.01.01".to_string();
+        let policy = TrustPolicy::email_protection().at(date("2022-04-04"));
+        assert!(policy.is_trusted(&metadata).unwrap());
+        let policy = TrustPolicy::email_protection().at(date("2022-04-05"));
+        assert!(!policy.is_trusted(&metadata).unwrap());
+
+        // The email protection policy does not inherit the TLS exclusions.
+        let excluded = CertificateMetadata {
+            sha256_fingerprint: EXCLUDED_FINGERPRINTS[0].to_string(),
+            ..test_metadata()
+        };
+        assert!(!TrustPolicy::default().is_trusted(&excluded).unwrap());
+        let excluded = CertificateMetadata {
+            trust_bits: "Email".to_string(),
+            ..excluded
+        };
+        assert!(TrustPolicy::email_protection()
+            .is_trusted(&excluded)
+            .unwrap());
+
+        // Extra exclusions, matched regardless of case or separators.
+        metadata.distrust_for_tls_after_date = "".to_string();
+        assert!(TrustPolicy::default().is_trusted(&metadata).unwrap());
+        let policy = TrustPolicy::default().exclude("aa");
+        assert!(!policy.is_trusted(&metadata).unwrap());
+
+        // Forced inclusions override exclusions, trust bits and distrust dates.
+        let policy = TrustPolicy::default().exclude("AA").include("aa");
+        assert!(policy.is_trusted(&metadata).unwrap());
+        metadata.sha256_fingerprint = EXCLUDED_FINGERPRINTS[0].to_string();
+        assert!(!TrustPolicy::default().is_trusted(&metadata).unwrap());
+        metadata.trust_bits = "All Trust Bits Turned Off".to_string();
+        let policy = TrustPolicy::default().include(EXCLUDED_FINGERPRINTS[0]);
+        assert!(policy.is_trusted(&metadata).unwrap());
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use aws_lc_rs::digest;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{read_ccadb_csv, CertificateMetadata, Error, NameConstraints};
+
+/// A fetched copy of a CCADB CSV report, with where and when it was fetched from.
+///
+/// Generating code from a snapshot (see [`crate::codegen::Generator`]) records its
+/// [`Snapshot::sha256()`] in the generated code, identifying the report it was generated from.
+/// Roots are selected as of [`Snapshot::fetched_at()`], so the same snapshot always generates
+/// the same code.
+///
+/// A snapshot is stored as a directory holding the unmodified CSV report (in
+/// [`Snapshot::CSV_FILE`]) and a TOML manifest (in [`Snapshot::MANIFEST_FILE`]) with the
+/// source URL, fetch time and SHA-256 hash of the report. The hash can be checked with standard
+/// tools, e.g. `sha256sum ccadb.csv`.
+///
+/// The manifest also records any extra name constraints to generate for some roots (see
+/// [`Snapshot::with_name_constraints()`]), so they are generated from the snapshot like the roots
+/// themselves.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Snapshot {
+    source_url: String,
+    fetched_at: DateTime<Utc>,
+    csv: String,
+    name_constraints: BTreeMap<String, Vec<u8>>,
+}
+
+impl Snapshot {
+    /// The name of the file holding the CSV report in a snapshot directory.
+    pub const CSV_FILE: &'static str = "ccadb.csv";
+
+    /// The name of the file holding the manifest in a snapshot directory.
+    pub const MANIFEST_FILE: &'static str = "ccadb.lock";
+
+    /// Create a snapshot of the `csv` report fetched from `source_url` at `fetched_at`.
+    pub fn new(source_url: impl Into<String>, fetched_at: DateTime<Utc>, csv: String) -> Self {
+        Self {
+            source_url: source_url.into(),
+            fetched_at,
+            csv,
+            name_constraints: BTreeMap::new(),
+        }
+    }
+
+    /// Limit the root with the given (hex) SHA-256 fingerprint to the names permitted by the
+    /// `name_constraints`, in the DER form used by webpki trust anchors (see
+    /// [`NameConstraints::to_der()`]), replacing any extra constraints already given for it.
+    ///
+    /// Code generated from the snapshot combines them with any name constraints the CCADB applies
+    /// to the root. Returns an error if the fingerprint or name constraints are invalid.
+    pub fn with_name_constraints(
+        mut self,
+        sha256_fingerprint: &str,
+        name_constraints: Vec<u8>,
+    ) -> Result<Self, Error> {
+        match hex::decode(sha256_fingerprint) {
+            Ok(fingerprint) if fingerprint.len() == 32 => {}
+            _ => return Err(Error::InvalidFingerprint(sha256_fingerprint.to_string())),
+        }
+        NameConstraints::from_der(&name_constraints)?;
+        self.name_constraints
+            .insert(sha256_fingerprint.to_ascii_uppercase(), name_constraints);
+        Ok(self)
+    }
+
+    /// Read the snapshot stored in the directory `dir`.
+    ///
+    /// Returns an error if either file can not be read, the manifest is invalid, or the CSV
+    /// report does not match the hash in the manifest.
+    pub fn read(dir: &Path) -> Result<Self, Error> {
+        let manifest = fs::read_to_string(dir.join(Self::MANIFEST_FILE))?;
+        let csv = fs::read_to_string(dir.join(Self::CSV_FILE))?;
+
+        let manifest = toml::from_str::<Manifest>(&manifest)
+            .map_err(|err| Error::InvalidSnapshot(format!("invalid manifest: {err}")))?;
+        let mut snapshot = Self::new(manifest.source_url, manifest.fetched_at, csv);
+        let sha256 = manifest.sha256;
+        if !sha256.eq_ignore_ascii_case(&snapshot.sha256_hex()) {
+            return Err(Error::SnapshotHashMismatch(sha256));
+        }
+
+        for (fingerprint, der) in manifest.name_constraints {
+            let der = hex::decode(&der).map_err(|_| {
+                Error::InvalidSnapshot(format!("invalid name constraints for {fingerprint}"))
+            })?;
+            snapshot = snapshot.with_name_constraints(&fingerprint, der)?;
+        }
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot to the directory `dir`, creating it if necessary.
+    pub fn write(&self, dir: &Path) -> Result<(), Error> {
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(Self::CSV_FILE), &self.csv)?;
+        fs::write(dir.join(Self::MANIFEST_FILE), self.manifest())?;
+        Ok(())
+    }
+
+    /// Returns the contents of the manifest file of the snapshot, in TOML.
+    pub fn manifest(&self) -> String {
+        let manifest = Manifest {
+            source_url: self.source_url.clone(),
+            fetched_at: self.fetched_at,
+            sha256: self.sha256_hex(),
+            name_constraints: self
+                .name_constraints
+                .iter()
+                .map(|(fingerprint, der)| (fingerprint.clone(), hex::encode(der)))
+                .collect(),
+        };
+        format!(
+            "# A snapshot of a CCADB report, written by webpki-ccadb. Don't edit it.\n{}",
+            toml::to_string(&manifest).expect("manifest is always valid TOML")
+        )
+    }
+
+    /// Parse every certificate in the CSV report, without any filtering.
+    pub fn metadata(&self) -> Result<Vec<CertificateMetadata>, Error> {
+        read_ccadb_csv(self.csv.as_bytes())
+    }
+
+    /// Returns the SHA-256 hash of the CSV report.
+    pub fn sha256(&self) -> [u8; 32] {
+        let digest = digest::digest(&digest::SHA256, self.csv.as_bytes());
+        digest.as_ref().try_into().unwrap()
+    }
+
+    /// Returns the SHA-256 hash of the CSV report, as lowercase hex.
+    pub fn sha256_hex(&self) -> String {
+        hex::encode(self.sha256())
+    }
+
+    /// Returns the URL the report was fetched from.
+    pub fn source_url(&self) -> &str {
+        &self.source_url
+    }
+
+    /// Returns the time the report was fetched.
+    pub fn fetched_at(&self) -> DateTime<Utc> {
+        self.fetched_at
+    }
+
+    /// Returns the unmodified CSV report.
+    pub fn csv(&self) -> &str {
+        &self.csv
+    }
+
+    /// Returns the extra name constraints of roots, in DER, by uppercase hex SHA-256 fingerprint.
+    pub fn name_constraints(&self) -> &BTreeMap<String, Vec<u8>> {
+        &self.name_constraints
+    }
+}
+
+/// The manifest of a snapshot directory.
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    source_url: String,
+    fetched_at: DateTime<Utc>,
+    sha256: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    name_constraints: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::str::FromStr;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let snapshot = Snapshot::new(
+            "https://ccadb.example/report.csv",
+            Utc.with_ymd_and_hms(2024, 6, 30, 12, 34, 56).unwrap(),
+            "Common Name or Certificate Name\n".to_string(),
+        );
+        assert_eq!(
+            snapshot.manifest(),
+            concat!(
+                "# A snapshot of a CCADB report, written by webpki-ccadb. Don't edit it.\n",
+                "source_url = \"https://ccadb.example/report.csv\"\n",
+                "fetched_at = \"2024-06-30T12:34:56Z\"\n",
+                "sha256 = \"e3b638584e646ff0d110aa5dae8b561c73bafc37be2af4f2237970c9dea47093\"\n",
+            )
+        );
+
+        let dir = env::temp_dir().join(format!("webpki-ccadb-snapshot-{}", std::process::id()));
+        snapshot.write(&dir).unwrap();
+        assert_eq!(Snapshot::read(&dir).unwrap(), snapshot);
+
+        // Values are escaped.
+        let quoted = Snapshot::new("a \"quoted\"\\path\n", snapshot.fetched_at(), String::new());
+        quoted.write(&dir).unwrap();
+        assert_eq!(Snapshot::read(&dir).unwrap(), quoted);
+
+        // Extra name constraints are recorded in the manifest.
+        let der = NameConstraints::from_str("DNS:.example").unwrap().to_der();
+        let constrained = snapshot
+            .clone()
+            .with_name_constraints(&"ab".repeat(32), der.clone())
+            .unwrap();
+        assert!(constrained.manifest().ends_with(&format!(
+            "\n[name_constraints]\n{} = \"{}\"\n",
+            "AB".repeat(32),
+            hex::encode(&der)
+        )));
+        constrained.write(&dir).unwrap();
+        assert_eq!(Snapshot::read(&dir).unwrap(), constrained);
+
+        let err = snapshot
+            .clone()
+            .with_name_constraints("ab", der.clone())
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidFingerprint(_)));
+        let err = snapshot
+            .clone()
+            .with_name_constraints(&"ab".repeat(32), vec![0x30])
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidNameConstraints(_)));
+
+        // Changing the report invalidates the snapshot.
+        fs::write(dir.join(Snapshot::CSV_FILE), "changed\n").unwrap();
+        let err = Snapshot::read(&dir).unwrap_err();
+        assert!(matches!(err, Error::SnapshotHashMismatch(_)));
+
+        fs::write(dir.join(Snapshot::MANIFEST_FILE), "sha256 = unquoted\n").unwrap();
+        let err = Snapshot::read(&dir).unwrap_err();
+        assert!(matches!(err, Error::InvalidSnapshot(_)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
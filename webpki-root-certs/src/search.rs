@@ -0,0 +1,92 @@
+//! Binary search of the generated index tables, in a `const fn`.
+//
+// This module is generated identically in each crate by webpki-ccadb. Don't edit it.
+
+use core::cmp::Ordering;
+
+/// Binary search `index` (sorted by key) for the first entry with `key`, and return its index.
+pub(crate) const fn search(index: &[(&[u8], usize)], key: &[u8]) -> Option<usize> {
+    let (mut low, mut high) = (0, index.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match compare(index[mid].0, key) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Equal | Ordering::Greater => high = mid,
+        }
+    }
+
+    match low < index.len() {
+        true => match compare(index[low].0, key) {
+            Ordering::Equal => Some(index[low].1),
+            _ => None,
+        },
+        false => None,
+    }
+}
+
+/// Binary search `index` (sorted by fingerprint) for the entry with `fingerprint`, and return
+/// its index.
+pub(crate) const fn search_sha256(
+    index: &[([u8; 32], usize)],
+    fingerprint: &[u8; 32],
+) -> Option<usize> {
+    let (mut low, mut high) = (0, index.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match compare(&index[mid].0, fingerprint) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Some(index[mid].1),
+        }
+    }
+    None
+}
+
+/// Lexicographically compare `a` and `b`.
+const fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] < b[i] {
+            return Ordering::Less;
+        } else if a[i] > b[i] {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// If `der` is a single DER encoded SEQUENCE, returns its contents. Otherwise returns `der`.
+///
+/// The trust anchor representation elides the outer SEQUENCE of the subject and subject public
+/// key info, which is never a single SEQUENCE itself: a `Name`'s contents are SETs, and a
+/// `SubjectPublicKeyInfo`'s contents are an `AlgorithmIdentifier` followed by a BIT STRING.
+pub(crate) const fn strip_sequence(der: &[u8]) -> &[u8] {
+    let (header_len, len) = match der {
+        [0x30, len @ 0..=0x7f, ..] => (2, *len as usize),
+        [0x30, 0x81, a, ..] => (3, *a as usize),
+        [0x30, 0x82, a, b, ..] => (4, (*a as usize) << 8 | *b as usize),
+        [0x30, 0x83, a, b, c, ..] => (5, (*a as usize) << 16 | (*b as usize) << 8 | *c as usize),
+        _ => return der,
+    };
+    if header_len + len != der.len() {
+        return der;
+    }
+
+    let mut contents = der;
+    let mut skip = header_len;
+    while skip > 0 {
+        if let [_, rest @ ..] = contents {
+            contents = rest;
+        }
+        skip -= 1;
+    }
+    contents
+}
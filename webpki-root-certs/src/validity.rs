@@ -0,0 +1,24 @@
+use pki_types::{CertificateDer, UnixTime};
+
+use crate::{TLS_SERVER_ROOT_CERTS, VALIDITY};
+
+/// Returns the certificates in [`TLS_SERVER_ROOT_CERTS`] that are valid at `time`.
+///
+/// A certificate that has expired (or is not yet valid) can not validate any certificate chain
+/// at `time`, so long running deployments can use this to leave such roots out of their trust
+/// store.
+///
+/// ```rust
+/// use pki_types::UnixTime;
+///
+/// let roots = webpki_root_certs::roots_valid_at(UnixTime::now()).collect::<Vec<_>>();
+/// assert!(!roots.is_empty());
+/// ```
+pub fn roots_valid_at(time: UnixTime) -> impl Iterator<Item = &'static CertificateDer<'static>> {
+    let secs = time.as_secs();
+    TLS_SERVER_ROOT_CERTS
+        .iter()
+        .zip(VALIDITY)
+        .filter(move |(_, &(not_before, not_after))| not_before <= secs && secs <= not_after)
+        .map(|(cert, _)| cert)
+}
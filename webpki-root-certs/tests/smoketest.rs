@@ -11,3 +11,71 @@ fn test_trust_anchor_der() {
         webpki::anchor_from_trusted_cert(root).unwrap();
     }
 }
+
+#[test]
+fn test_lookups() {
+    use webpki_root_certs::{find_by_sha256, find_by_spki, find_by_subject, TLS_SERVER_ROOT_CERTS};
+
+    for (index, root) in TLS_SERVER_ROOT_CERTS.iter().enumerate() {
+        let fingerprint = aws_lc_rs::digest::digest(&aws_lc_rs::digest::SHA256, root.as_ref());
+        let fingerprint = <[u8; 32]>::try_from(fingerprint.as_ref()).unwrap();
+        assert_eq!(find_by_sha256(&fingerprint), Some(index));
+
+        // Subjects and keys may be shared between roots, so only check the match.
+        let (_, cert) = x509_parser::parse_x509_certificate(root.as_ref()).unwrap();
+        let found = find_by_subject(cert.subject().as_raw()).unwrap();
+        let (_, found_cert) =
+            x509_parser::parse_x509_certificate(TLS_SERVER_ROOT_CERTS[found].as_ref()).unwrap();
+        assert_eq!(found_cert.subject().as_raw(), cert.subject().as_raw());
+
+        let found = find_by_spki(cert.public_key().raw).unwrap();
+        let (_, found_cert) =
+            x509_parser::parse_x509_certificate(TLS_SERVER_ROOT_CERTS[found].as_ref()).unwrap();
+        assert_eq!(found_cert.public_key().raw, cert.public_key().raw);
+    }
+
+    assert_eq!(find_by_sha256(&[0; 32]), None);
+}
+
+#[test]
+fn test_roots_valid_at() {
+    use core::time::Duration;
+
+    use pki_types::UnixTime;
+    use webpki_root_certs::{roots_valid_at, TLS_SERVER_ROOT_CERTS};
+
+    // Check the roots valid at the `notBefore` and `notAfter` of each root, and just outside.
+    for root in TLS_SERVER_ROOT_CERTS {
+        let (_, cert) = x509_parser::parse_x509_certificate(root.as_ref()).unwrap();
+        let validity = cert.validity();
+        let not_before = validity.not_before.timestamp() as u64;
+        let not_after = validity.not_after.timestamp() as u64;
+        for secs in [not_before - 1, not_before, not_after, not_after + 1] {
+            let time = UnixTime::since_unix_epoch(Duration::from_secs(secs));
+            let valid = (not_before..=not_after).contains(&secs);
+            assert_eq!(roots_valid_at(time).any(|found| found == root), valid);
+        }
+    }
+
+    let time = UnixTime::since_unix_epoch(Duration::from_secs(0));
+    assert_eq!(roots_valid_at(time).count(), 0);
+}
+
+#[test]
+fn test_extra_roots() {
+    use pki_types::pem::PemObject;
+    use pki_types::CertificateDer;
+    use webpki_root_certs::EXTRA_ROOTS;
+
+    // The test is built with the same environment as the library.
+    let Some(path) = option_env!("WEBPKI_ROOTS_EXTRA_PEM") else {
+        assert!(EXTRA_ROOTS.is_empty());
+        return;
+    };
+
+    let pem = std::fs::read(path).unwrap();
+    let certs = CertificateDer::pem_slice_iter(&pem)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(certs, EXTRA_ROOTS);
+}
@@ -0,0 +1,23 @@
+//! Generates the `EXTRA_ROOTS` array from the PEM bundle named by `WEBPKI_ROOTS_EXTRA_PEM` (if
+//! any), with the `extra-roots` feature.
+
+#[cfg(feature = "extra-roots")]
+fn main() {
+    webpki_ccadb::codegen::Crate::WebpkiRoots.build_extra_roots();
+}
+
+/// Without the `extra-roots` feature there are no extra roots, and nothing to parse them with.
+#[cfg(not(feature = "extra-roots"))]
+fn main() {
+    use std::path::PathBuf;
+    use std::{env, fs};
+
+    const EXTRA_PEM_VAR: &str = "WEBPKI_ROOTS_EXTRA_PEM";
+    println!("cargo:rerun-if-env-changed={EXTRA_PEM_VAR}");
+    if env::var_os(EXTRA_PEM_VAR).is_some() {
+        println!("cargo:warning={EXTRA_PEM_VAR} is ignored without the `extra-roots` feature");
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("extra_roots.rs");
+    fs::write(out, "&[]\n").unwrap();
+}
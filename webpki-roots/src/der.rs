@@ -0,0 +1,68 @@
+//! A minimal DER reader.
+//!
+//! This is used by the `DistrustAfterVerifier` to read the validity of certificates.
+
+/// A DER encoded value.
+#[derive(Clone, Copy)]
+pub(crate) struct Tlv<'a> {
+    /// The tag of the value.
+    pub(crate) tag: u8,
+    /// The contents of the value.
+    pub(crate) contents: &'a [u8],
+}
+
+/// Read a DER encoded value from the front of `input`, returning it and the rest of `input`.
+///
+/// Lengths must be minimally encoded, in at most four bytes.
+pub(crate) fn read(input: &[u8]) -> Option<(Tlv<'_>, &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, mut rest) = rest.split_first()?;
+    let len = match first {
+        len @ 0..=0x7f => usize::from(len),
+        0x81..=0x84 => {
+            let (bytes, after) = split_at(rest, usize::from(first & 0x7f))?;
+            rest = after;
+            let len = bytes.iter().try_fold(0usize, |len, &b| {
+                len.checked_mul(0x100).map(|len| len | usize::from(b))
+            })?;
+            // Longer forms than needed are not DER.
+            if bytes[0] == 0 || len < 0x80 {
+                return None;
+            }
+            len
+        }
+        _ => return None,
+    };
+
+    let (contents, rest) = split_at(rest, len)?;
+    Some((Tlv { tag, contents }, rest))
+}
+
+/// Split a DER encoded value with the given `tag` off the front of `input`, returning its
+/// contents and the remaining input.
+pub(crate) fn value(input: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    match read(input)? {
+        (tlv, rest) if tlv.tag == tag => Some((tlv.contents, rest)),
+        _ => None,
+    }
+}
+
+/// Skip a DER encoded value with the given `tag` at the front of `input`, if there is one.
+pub(crate) fn skip_optional(input: &[u8], tag: u8) -> Option<&[u8]> {
+    match input.first() == Some(&tag) {
+        true => value(input, tag).map(|(_, rest)| rest),
+        false => Some(input),
+    }
+}
+
+fn split_at(input: &[u8], mid: usize) -> Option<(&[u8], &[u8])> {
+    match mid <= input.len() {
+        true => Some(input.split_at(mid)),
+        false => None,
+    }
+}
+
+pub(crate) const INTEGER: u8 = 0x02;
+pub(crate) const UTC_TIME: u8 = 0x17;
+pub(crate) const GENERALIZED_TIME: u8 = 0x18;
+pub(crate) const SEQUENCE: u8 = 0x30;
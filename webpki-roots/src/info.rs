@@ -0,0 +1,42 @@
+use pki_types::UnixTime;
+
+/// Metadata about a root certificate, from the CCADB and the certificate itself.
+///
+/// [`TLS_SERVER_ROOTS_INFO`](crate::TLS_SERVER_ROOTS_INFO) holds the metadata of each root in
+/// [`TLS_SERVER_ROOTS`](crate::TLS_SERVER_ROOTS), at the same index.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RootInfo {
+    /// The CCADB common name or certificate name label of the certificate.
+    pub label: &'static str,
+
+    /// The SHA256 fingerprint of the certificate's DER encoding.
+    pub sha256_fingerprint: [u8; 32],
+
+    /// The big-endian bytes of the certificate's serial number, without leading zeros.
+    pub serial: &'static [u8],
+
+    /// The certificate issuer's common name, organization and organizational unit.
+    pub issuer: &'static str,
+
+    /// The certificate subject's common name, organization and organizational unit.
+    pub subject: &'static str,
+
+    /// The end of the CCADB "Distrust for TLS After Date" of the certificate (if any).
+    ///
+    /// See [`TLS_SERVER_ROOT_DISTRUST_AFTER`](crate::TLS_SERVER_ROOT_DISTRUST_AFTER).
+    pub tls_distrust_after: Option<UnixTime>,
+
+    /// The time before which the certificate itself is not yet valid (its `notBefore`).
+    pub not_before: UnixTime,
+
+    /// The time after which the certificate itself is no longer valid (its `notAfter`).
+    pub not_after: UnixTime,
+}
+
+impl RootInfo {
+    /// Returns true if `time` is within the validity period of the certificate.
+    pub fn is_valid_at(&self, time: UnixTime) -> bool {
+        self.not_before <= time && time <= self.not_after
+    }
+}
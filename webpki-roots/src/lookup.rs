@@ -0,0 +1,43 @@
+use crate::search::{search, search_sha256, strip_sequence};
+use crate::{SHA256_INDEX, SPKI_INDEX, SUBJECT_INDEX};
+
+/// Returns the index in [`TLS_SERVER_ROOTS`] of the root whose certificate has the given
+/// SHA-256 fingerprint, if any.
+///
+/// The same index can be used with [`TLS_SERVER_ROOTS_INFO`].
+///
+/// ```rust
+/// let fingerprint = [0u8; 32];
+/// assert_eq!(webpki_roots::find_by_sha256(&fingerprint), None);
+/// ```
+///
+/// [`TLS_SERVER_ROOTS`]: crate::TLS_SERVER_ROOTS
+/// [`TLS_SERVER_ROOTS_INFO`]: crate::TLS_SERVER_ROOTS_INFO
+pub const fn find_by_sha256(fingerprint: &[u8; 32]) -> Option<usize> {
+    search_sha256(SHA256_INDEX, fingerprint)
+}
+
+/// Returns the index in [`TLS_SERVER_ROOTS`] of a root with the given DER encoded subject, if
+/// any.
+///
+/// `subject` may be a full `Name`, or its contents as in [`TrustAnchor::subject`]. If several
+/// roots have the same subject, the lowest index is returned.
+///
+/// [`TLS_SERVER_ROOTS`]: crate::TLS_SERVER_ROOTS
+/// [`TrustAnchor::subject`]: pki_types::TrustAnchor::subject
+pub const fn find_by_subject(subject: &[u8]) -> Option<usize> {
+    search(SUBJECT_INDEX, strip_sequence(subject))
+}
+
+/// Returns the index in [`TLS_SERVER_ROOTS`] of a root with the given DER encoded subject
+/// public key info, if any.
+///
+/// `spki` may be a full `SubjectPublicKeyInfo`, or its contents as in
+/// [`TrustAnchor::subject_public_key_info`]. If several roots have the same key, the lowest
+/// index is returned.
+///
+/// [`TLS_SERVER_ROOTS`]: crate::TLS_SERVER_ROOTS
+/// [`TrustAnchor::subject_public_key_info`]: pki_types::TrustAnchor::subject_public_key_info
+pub const fn find_by_spki(spki: &[u8]) -> Option<usize> {
+    search(SPKI_INDEX, strip_sequence(spki))
+}
@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+
+use pki_types::TrustAnchor;
+
+use crate::{RootInfo, TLS_SERVER_ROOTS, TLS_SERVER_ROOTS_INFO};
+
+/// A subset of [`TLS_SERVER_ROOTS`], selected by the metadata in [`TLS_SERVER_ROOTS_INFO`].
+///
+/// Start with [`RootSet::all()`] and exclude roots that should not be trusted:
+///
+/// ```rust
+/// use webpki_roots::{RootSet, TLS_SERVER_ROOTS, TLS_SERVER_ROOTS_INFO};
+///
+/// let fingerprint = TLS_SERVER_ROOTS_INFO[0].sha256_fingerprint;
+/// let anchors = RootSet::all().without_fingerprint(&fingerprint).trust_anchors();
+/// assert_eq!(anchors.len(), TLS_SERVER_ROOTS.len() - 1);
+///
+/// // Exclude roots by any other metadata, e.g. those with DigiCert in their subject.
+/// let set = RootSet::all().retain(|info| !info.subject.contains("DigiCert"));
+/// assert!(!set.is_empty() && set.len() < TLS_SERVER_ROOTS.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RootSet {
+    indices: Vec<usize>,
+}
+
+impl RootSet {
+    /// Returns the set of all roots in [`TLS_SERVER_ROOTS`].
+    pub fn all() -> Self {
+        Self {
+            indices: (0..TLS_SERVER_ROOTS.len()).collect(),
+        }
+    }
+
+    /// Exclude the root with the given SHA-256 fingerprint (if it is in the set).
+    pub fn without_fingerprint(self, sha256_fingerprint: &[u8; 32]) -> Self {
+        self.retain(|info| info.sha256_fingerprint != *sha256_fingerprint)
+    }
+
+    /// Keep only the roots for which `keep` returns true.
+    pub fn retain(mut self, mut keep: impl FnMut(&RootInfo) -> bool) -> Self {
+        self.indices
+            .retain(|&index| keep(&TLS_SERVER_ROOTS_INFO[index]));
+        self
+    }
+
+    /// Returns the metadata of the roots in the set, in [`TLS_SERVER_ROOTS`] order.
+    pub fn iter(&self) -> impl Iterator<Item = &'static RootInfo> + '_ {
+        self.indices
+            .iter()
+            .map(|&index| &TLS_SERVER_ROOTS_INFO[index])
+    }
+
+    /// Returns the trust anchors of the roots in the set, in [`TLS_SERVER_ROOTS`] order.
+    pub fn trust_anchors(&self) -> Vec<TrustAnchor<'static>> {
+        self.indices
+            .iter()
+            .map(|&index| TLS_SERVER_ROOTS[index].clone())
+            .collect()
+    }
+
+    /// Returns the number of roots in the set.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns true if there are no roots in the set.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use pki_types::CertificateDer;
+use rustls::RootCertStore;
+
+use crate::{EXTRA_ROOTS, TLS_SERVER_ROOTS};
+
+/// Returns a [`RootCertStore`] containing [`TLS_SERVER_ROOTS`] and [`EXTRA_ROOTS`].
+///
+/// ```rust
+/// let root_store = webpki_roots::root_cert_store();
+/// assert_eq!(
+///     root_store.len(),
+///     webpki_roots::TLS_SERVER_ROOTS.len() + webpki_roots::EXTRA_ROOTS.len()
+/// );
+/// ```
+pub fn root_cert_store() -> RootCertStore {
+    RootCertStore {
+        roots: TLS_SERVER_ROOTS
+            .iter()
+            .chain(EXTRA_ROOTS)
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Returns a [`RootCertStore`] containing [`TLS_SERVER_ROOTS`], [`EXTRA_ROOTS`] and the
+/// `extra` root certificates.
+///
+/// Returns an error for every extra certificate that can not be parsed as a trust anchor, with
+/// its position in `extra`, if there are any.
+pub fn root_cert_store_with<'a>(
+    extra: impl IntoIterator<Item = CertificateDer<'a>>,
+) -> Result<RootCertStore, Vec<InvalidExtraRoot>> {
+    let mut store = root_cert_store();
+    let mut errors = Vec::new();
+    for (index, cert) in extra.into_iter().enumerate() {
+        if let Err(error) = store.add(cert) {
+            errors.push(InvalidExtraRoot { index, error });
+        }
+    }
+
+    match errors.is_empty() {
+        true => Ok(store),
+        false => Err(errors),
+    }
+}
+
+/// An extra root certificate passed to [`root_cert_store_with()`] that could not be added.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct InvalidExtraRoot {
+    /// The position of the certificate in the extra roots.
+    pub index: usize,
+
+    /// Why the certificate could not be added.
+    pub error: rustls::Error,
+}
+
+impl fmt::Display for InvalidExtraRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid extra root at index {}: {}",
+            self.index, self.error
+        )
+    }
+}
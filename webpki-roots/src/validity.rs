@@ -0,0 +1,25 @@
+use pki_types::{TrustAnchor, UnixTime};
+
+use crate::{TLS_SERVER_ROOTS, TLS_SERVER_ROOTS_INFO};
+
+/// Returns the roots in [`TLS_SERVER_ROOTS`] whose certificates are valid at `time`.
+///
+/// A root whose certificate has expired (or is not yet valid) can not validate any certificate
+/// chain at `time`, so long running deployments can use this to leave such roots out of their
+/// trust store. See [`RootInfo::is_valid_at()`].
+///
+/// ```rust
+/// use pki_types::UnixTime;
+///
+/// let roots = webpki_roots::roots_valid_at(UnixTime::now()).collect::<Vec<_>>();
+/// assert!(!roots.is_empty());
+/// ```
+///
+/// [`RootInfo::is_valid_at()`]: crate::RootInfo::is_valid_at
+pub fn roots_valid_at(time: UnixTime) -> impl Iterator<Item = &'static TrustAnchor<'static>> {
+    TLS_SERVER_ROOTS
+        .iter()
+        .zip(TLS_SERVER_ROOTS_INFO)
+        .filter(move |(_, info)| info.is_valid_at(time))
+        .map(|(anchor, _)| anchor)
+}
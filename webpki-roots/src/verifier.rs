@@ -0,0 +1,261 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::time::Duration;
+
+use pki_types::{CertificateDer, ServerName, TrustAnchor, UnixTime};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::WebPkiSupportedAlgorithms;
+use rustls::{CertificateError, DigitallySignedStruct, DistinguishedName, Error, SignatureScheme};
+use webpki::{EndEntityCert, KeyUsage};
+
+use crate::der;
+use crate::{TLS_SERVER_ROOTS, TLS_SERVER_ROOT_DISTRUST_AFTER};
+
+/// A [`ServerCertVerifier`] that enforces the distrust after dates of the compiled-in roots.
+///
+/// Certificate chains are first verified by the wrapped verifier. Chains that it accepts are
+/// then rejected if the end-entity certificate was issued (per its `notBefore`) after the
+/// distrust after date of a root, and every valid path for the chain is anchored at such a
+/// root. See [`TLS_SERVER_ROOT_DISTRUST_AFTER`].
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use rustls::client::WebPkiServerVerifier;
+/// let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+/// let roots = rustls::RootCertStore {
+///     roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+/// };
+/// let inner = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+///     .build()
+///     .unwrap();
+/// let verifier = webpki_roots::DistrustAfterVerifier::new(
+///     inner,
+///     provider.signature_verification_algorithms,
+/// );
+/// ```
+#[derive(Debug)]
+pub struct DistrustAfterVerifier {
+    inner: Arc<dyn ServerCertVerifier>,
+    supported_algs: WebPkiSupportedAlgorithms,
+    roots: &'static [TrustAnchor<'static>],
+    distrust_after: &'static [(usize, UnixTime)],
+}
+
+impl DistrustAfterVerifier {
+    /// Wrap `inner`, enforcing [`TLS_SERVER_ROOT_DISTRUST_AFTER`] for [`TLS_SERVER_ROOTS`].
+    ///
+    /// `supported_algs` are used to build the certificate path of chains accepted by `inner`,
+    /// and will typically be those of the crypto provider used by `inner`.
+    pub fn new(
+        inner: Arc<dyn ServerCertVerifier>,
+        supported_algs: WebPkiSupportedAlgorithms,
+    ) -> Self {
+        Self::with_roots(
+            inner,
+            supported_algs,
+            TLS_SERVER_ROOTS,
+            TLS_SERVER_ROOT_DISTRUST_AFTER,
+        )
+    }
+
+    /// Wrap `inner`, enforcing `distrust_after` for `roots`.
+    ///
+    /// Each entry of `distrust_after` holds the index of a root in `roots` and the time after
+    /// which certificates issued by it are distrusted, as in [`TLS_SERVER_ROOT_DISTRUST_AFTER`].
+    pub fn with_roots(
+        inner: Arc<dyn ServerCertVerifier>,
+        supported_algs: WebPkiSupportedAlgorithms,
+        roots: &'static [TrustAnchor<'static>],
+        distrust_after: &'static [(usize, UnixTime)],
+    ) -> Self {
+        Self {
+            inner,
+            supported_algs,
+            roots,
+            distrust_after,
+        }
+    }
+
+    /// Returns true if the root at `index` is distrusted for certificates issued at `not_before`.
+    fn distrusted(&self, index: usize, not_before: UnixTime) -> bool {
+        self.distrust_after
+            .iter()
+            .any(|&(i, distrust_after)| i == index && not_before > distrust_after)
+    }
+}
+
+impl ServerCertVerifier for DistrustAfterVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, Error> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        )?;
+
+        let not_before = not_before(end_entity)
+            .ok_or(Error::InvalidCertificate(CertificateError::BadEncoding))?;
+        if !self
+            .distrust_after
+            .iter()
+            .any(|&(_, distrust_after)| not_before > distrust_after)
+        {
+            return Ok(verified);
+        }
+
+        // Look for a valid path that isn't anchored at a root distrusted for this certificate.
+        // The callback is only called for paths that are otherwise valid, so if it has rejected
+        // a path and no other was found, the chain can only be anchored at a distrusted root.
+        let rejected = Cell::new(false);
+        let verify_path = |path: &webpki::VerifiedPath<'_>| {
+            let anchor = path.anchor();
+            match self
+                .roots
+                .iter()
+                .position(|root| core::ptr::eq(root, anchor))
+            {
+                Some(index) if self.distrusted(index, not_before) => {
+                    rejected.set(true);
+                    Err(webpki::Error::UnknownIssuer)
+                }
+                _ => Ok(()),
+            }
+        };
+
+        let cert = EndEntityCert::try_from(end_entity)
+            .map_err(|_| Error::InvalidCertificate(CertificateError::BadEncoding))?;
+        let result = cert.verify_for_usage(
+            self.supported_algs.all,
+            self.roots,
+            intermediates,
+            now,
+            KeyUsage::server_auth(),
+            None,
+            Some(&verify_path),
+        );
+
+        match result {
+            Err(_) if rejected.get() => {
+                Err(Error::InvalidCertificate(CertificateError::UnknownIssuer))
+            }
+            // Either a trusted path was found, or the chain isn't anchored at any of our roots
+            // at all (in which case `inner` must have trusted it for reasons of its own).
+            _ => Ok(verified),
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+
+    fn requires_raw_public_keys(&self) -> bool {
+        self.inner.requires_raw_public_keys()
+    }
+
+    fn root_hint_subjects(&self) -> Option<&[DistinguishedName]> {
+        self.inner.root_hint_subjects()
+    }
+}
+
+/// Returns the `notBefore` time of the DER encoded X.509 certificate `cert`.
+fn not_before(cert: &[u8]) -> Option<UnixTime> {
+    // Certificate ::= SEQUENCE { tbsCertificate TBSCertificate, ... }
+    let (cert, _) = der::value(cert, der::SEQUENCE)?;
+    let (tbs, _) = der::value(cert, der::SEQUENCE)?;
+
+    // TBSCertificate ::= SEQUENCE { version [0] EXPLICIT Version DEFAULT v1, serialNumber,
+    //   signature, issuer, validity, ... }
+    let mut rest = der::skip_optional(tbs, CONTEXT_SPECIFIC_CONSTRUCTED_0)?;
+    rest = der::value(rest, der::INTEGER)?.1;
+    rest = der::value(rest, der::SEQUENCE)?.1;
+    rest = der::value(rest, der::SEQUENCE)?.1;
+
+    // Validity ::= SEQUENCE { notBefore Time, notAfter Time }
+    let (validity, _) = der::value(rest, der::SEQUENCE)?;
+    let (time, _) = der::read(validity)?;
+    let digits = match time.tag {
+        der::UTC_TIME => 2,
+        der::GENERALIZED_TIME => 4,
+        _ => return None,
+    };
+    let time = time.contents;
+
+    // YYMMDDHHMMSSZ or YYYYMMDDHHMMSSZ
+    if time.len() != digits + 11 || time[digits + 10] != b'Z' {
+        return None;
+    }
+
+    let mut fields = [0u64; 6];
+    let mut at = 0;
+    for (i, field) in fields.iter_mut().enumerate() {
+        let len = if i == 0 { digits } else { 2 };
+        for &b in &time[at..at + len] {
+            if !b.is_ascii_digit() {
+                return None;
+            }
+            *field = *field * 10 + u64::from(b - b'0');
+        }
+        at += len;
+    }
+
+    let [mut year, month, day, hours, minutes, seconds] = fields;
+    if digits == 2 {
+        // RFC 5280 § 4.1.2.5.1: two digit years of 50 or more are in the 20th century.
+        year += if year >= 50 { 1900 } else { 2000 };
+    }
+    if !(1970..=9999).contains(&year)
+        || !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || seconds > 59
+    {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    let secs = ((days * 24 + hours) * 60 + minutes) * 60 + seconds;
+    Some(UnixTime::since_unix_epoch(Duration::from_secs(secs)))
+}
+
+/// Returns the number of days since 1970-01-01 of the given (post-epoch) proleptic Gregorian
+/// calendar date.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+const CONTEXT_SPECIFIC_CONSTRUCTED_0: u8 = 0xa0;
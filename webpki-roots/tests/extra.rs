@@ -0,0 +1,28 @@
+use std::fs;
+
+use pki_types::pem::PemObject;
+use pki_types::CertificateDer;
+use webpki::anchor_from_trusted_cert;
+
+use webpki_roots::EXTRA_ROOTS;
+
+#[test]
+fn extra_roots_match_bundle() {
+    // The test is built with the same environment and features as the library.
+    let path = match option_env!("WEBPKI_ROOTS_EXTRA_PEM") {
+        Some(path) if cfg!(feature = "extra-roots") => path,
+        _ => {
+            assert!(EXTRA_ROOTS.is_empty());
+            return;
+        }
+    };
+
+    let pem = fs::read(path).unwrap();
+    let certs = CertificateDer::pem_slice_iter(&pem)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(certs.len(), EXTRA_ROOTS.len());
+    for (cert, extra) in certs.iter().zip(EXTRA_ROOTS) {
+        assert_eq!(&anchor_from_trusted_cert(cert).unwrap(), extra);
+    }
+}
@@ -0,0 +1,138 @@
+use core::time::Duration;
+use std::collections::HashMap;
+use std::path::Path;
+
+use pki_types::UnixTime;
+use webpki_ccadb::codegen::Root;
+use webpki_ccadb::Snapshot;
+use x509_parser::prelude::FromDer;
+use x509_parser::x509::X509Name;
+
+use webpki_roots::{
+    roots_valid_at, RootInfo, TLS_SERVER_ROOTS, TLS_SERVER_ROOTS_ECDSA, TLS_SERVER_ROOTS_INFO,
+    TLS_SERVER_ROOTS_RSA, TLS_SERVER_ROOT_DISTRUST_AFTER,
+};
+
+#[test]
+fn info_matches_roots() {
+    assert_eq!(TLS_SERVER_ROOTS.len(), TLS_SERVER_ROOTS_INFO.len());
+
+    for (anchor, info) in TLS_SERVER_ROOTS.iter().zip(TLS_SERVER_ROOTS_INFO) {
+        // The trust anchor subject elides the outer SEQUENCE of the name, so wrap it up.
+        let wrapped_der = yasna::construct_der(|writer| {
+            writer.write_sequence(|writer| {
+                writer.next().write_der(anchor.subject.as_ref());
+            })
+        });
+        let (_, subject) = X509Name::from_der(&wrapped_der).unwrap();
+        if let Some(cn) = subject.iter_common_name().next() {
+            let cn = cn.as_str().unwrap();
+            assert!(
+                info.subject.contains(cn),
+                "{:?} does not match anchor subject {cn:?}",
+                info.label
+            );
+        }
+
+        assert!(!info.serial.is_empty());
+        assert!(info.not_before < info.not_after);
+    }
+
+    for &(index, distrust_after) in TLS_SERVER_ROOT_DISTRUST_AFTER {
+        assert_eq!(
+            TLS_SERVER_ROOTS_INFO[index].tls_distrust_after,
+            Some(distrust_after)
+        );
+    }
+}
+
+#[test]
+fn key_algorithm_subsets() {
+    // DER encoded OBJECT IDENTIFIERs for id-ecPublicKey and rsaEncryption.
+    const EC_PUBLIC_KEY: &[u8] = &[0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+    const RSA_ENCRYPTION: &[u8] = &[
+        0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01,
+    ];
+
+    for (subset, oid) in [
+        (TLS_SERVER_ROOTS_ECDSA, EC_PUBLIC_KEY),
+        (TLS_SERVER_ROOTS_RSA, RSA_ENCRYPTION),
+    ] {
+        for anchor in subset {
+            assert!(TLS_SERVER_ROOTS.contains(anchor));
+            // The SPKI starts with the AlgorithmIdentifier SEQUENCE, then its algorithm OID.
+            assert_eq!(&anchor.subject_public_key_info[2..2 + oid.len()], oid);
+        }
+    }
+
+    let classified = TLS_SERVER_ROOTS_ECDSA.len() + TLS_SERVER_ROOTS_RSA.len();
+    assert!(classified <= TLS_SERVER_ROOTS.len());
+    let other = TLS_SERVER_ROOTS.iter().filter(|anchor| {
+        !TLS_SERVER_ROOTS_ECDSA.contains(anchor) && !TLS_SERVER_ROOTS_RSA.contains(anchor)
+    });
+    assert_eq!(other.count(), TLS_SERVER_ROOTS.len() - classified);
+}
+
+#[test]
+fn roots_valid_at_matches_info() {
+    for info in TLS_SERVER_ROOTS_INFO {
+        for time in [info.not_before, info.not_after] {
+            let valid = roots_valid_at(time).collect::<Vec<_>>();
+            assert_eq!(
+                valid.len(),
+                TLS_SERVER_ROOTS_INFO
+                    .iter()
+                    .filter(|info| info.is_valid_at(time))
+                    .count()
+            );
+            assert!(valid.iter().all(|anchor| TLS_SERVER_ROOTS.contains(anchor)));
+        }
+    }
+
+    let epoch = UnixTime::since_unix_epoch(Duration::from_secs(0));
+    assert_eq!(roots_valid_at(epoch).count(), 0);
+}
+
+#[test]
+#[ignore = "depends on the current time; run with `--ignored` before a release"]
+fn no_expired_roots() {
+    let now = UnixTime::now();
+    let expired = TLS_SERVER_ROOTS_INFO
+        .iter()
+        .filter(|info| info.not_after.as_secs() < now.as_secs())
+        .map(|info| info.label)
+        .collect::<Vec<_>>();
+    assert!(expired.is_empty(), "expired roots: {expired:?}");
+}
+
+#[test]
+#[ignore = "needs the CCADB snapshot the code is generated from, in ccadb-snapshot"]
+fn distrust_after_matches_snapshot() {
+    // The snapshot the checked-in code is generated from, at the root of the workspace.
+    let snapshot = Snapshot::read(Path::new("../ccadb-snapshot")).unwrap();
+    let report = snapshot
+        .metadata()
+        .unwrap()
+        .into_iter()
+        .map(|metadata| (metadata.sha256_fingerprint.clone(), metadata))
+        .collect::<HashMap<_, _>>();
+
+    // Each root's "Distrust for TLS After Date" in the snapshot, at the end of that date.
+    let mut expected = Vec::new();
+    for (index, info) in TLS_SERVER_ROOTS_INFO.iter().enumerate() {
+        let metadata = &report[&hex_fingerprint(info)];
+        let root = Root::new(metadata.clone()).unwrap();
+        if let Some(secs) = root.tls_distrust_after_secs() {
+            let distrust_after = UnixTime::since_unix_epoch(Duration::from_secs(secs as u64));
+            expected.push((index, distrust_after));
+        }
+    }
+    assert_eq!(TLS_SERVER_ROOT_DISTRUST_AFTER, expected);
+}
+
+fn hex_fingerprint(info: &RootInfo) -> String {
+    info.sha256_fingerprint
+        .iter()
+        .map(|b| format!("{b:02X}"))
+        .collect()
+}
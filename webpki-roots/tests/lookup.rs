@@ -0,0 +1,47 @@
+use webpki_roots::{
+    find_by_sha256, find_by_spki, find_by_subject, TLS_SERVER_ROOTS, TLS_SERVER_ROOTS_INFO,
+};
+
+#[test]
+fn lookups_find_every_root() {
+    for (index, (anchor, info)) in TLS_SERVER_ROOTS
+        .iter()
+        .zip(TLS_SERVER_ROOTS_INFO)
+        .enumerate()
+    {
+        assert_eq!(find_by_sha256(&info.sha256_fingerprint), Some(index));
+
+        // Subjects and keys may be shared between roots, so only check the match.
+        let subject = anchor.subject.as_ref();
+        let found = find_by_subject(subject).unwrap();
+        assert_eq!(TLS_SERVER_ROOTS[found].subject.as_ref(), subject);
+        assert_eq!(find_by_subject(&wrap_in_sequence(subject)), Some(found));
+
+        let spki = anchor.subject_public_key_info.as_ref();
+        let found = find_by_spki(spki).unwrap();
+        assert_eq!(
+            TLS_SERVER_ROOTS[found].subject_public_key_info.as_ref(),
+            spki
+        );
+        assert_eq!(find_by_spki(&wrap_in_sequence(spki)), Some(found));
+    }
+}
+
+#[test]
+fn lookups_reject_unknown() {
+    const NOT_FOUND: Option<usize> = find_by_sha256(&[0; 32]);
+    assert_eq!(NOT_FOUND, None);
+    const NO_SUBJECT: Option<usize> = find_by_subject(&[0x30, 0x00]);
+    assert_eq!(NO_SUBJECT, None);
+    const NO_SPKI: Option<usize> = find_by_spki(&[0x30, 0x03, 0x02, 0x01]);
+    assert_eq!(NO_SPKI, None);
+    assert_eq!(find_by_subject(&[]), None);
+}
+
+fn wrap_in_sequence(der: &[u8]) -> Vec<u8> {
+    yasna::construct_der(|writer| {
+        writer.write_sequence(|writer| {
+            writer.next().write_der(der);
+        })
+    })
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "alloc")]
+
+use webpki_roots::{RootSet, TLS_SERVER_ROOTS, TLS_SERVER_ROOTS_INFO};
+
+#[test]
+fn root_set() {
+    let all = RootSet::all();
+    assert_eq!(all.len(), TLS_SERVER_ROOTS.len());
+    assert_eq!(all.trust_anchors(), TLS_SERVER_ROOTS);
+
+    // Excluding by fingerprint removes exactly that root.
+    let excluded = &TLS_SERVER_ROOTS_INFO[1];
+    let set = RootSet::all().without_fingerprint(&excluded.sha256_fingerprint);
+    assert_eq!(set.len(), TLS_SERVER_ROOTS.len() - 1);
+    assert!(set.iter().all(|info| info != excluded));
+    assert!(!set.trust_anchors().contains(&TLS_SERVER_ROOTS[1]));
+
+    // Unknown fingerprints are ignored.
+    let set = RootSet::all().without_fingerprint(&[0; 32]);
+    assert_eq!(set.len(), TLS_SERVER_ROOTS.len());
+
+    assert!(RootSet::all().retain(|_| false).is_empty());
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "rustls")]
+
+use pki_types::CertificateDer;
+use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, KeyPair};
+use rustls::{CertificateError, Error};
+
+use webpki_roots::{root_cert_store, root_cert_store_with, EXTRA_ROOTS, TLS_SERVER_ROOTS};
+
+#[test]
+fn root_cert_store_with_extra_roots() {
+    let roots = TLS_SERVER_ROOTS.len() + EXTRA_ROOTS.len();
+    assert_eq!(root_cert_store().len(), roots);
+
+    let mut params = CertificateParams::new([]).unwrap();
+    params
+        .distinguished_name
+        .push(DnType::CommonName, "Corporate Root");
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    let extra = params
+        .self_signed(&KeyPair::generate().unwrap())
+        .unwrap()
+        .der()
+        .clone();
+
+    let store = root_cert_store_with([extra.clone()]).unwrap();
+    assert_eq!(store.len(), roots + 1);
+
+    // Every invalid certificate is reported, with its position.
+    let invalid = CertificateDer::from(&b"not a certificate"[..]);
+    let errors = root_cert_store_with([invalid.clone(), extra, invalid]).unwrap_err();
+    assert_eq!(
+        errors.iter().map(|err| err.index).collect::<Vec<_>>(),
+        [0, 2]
+    );
+    assert!(matches!(
+        errors[0].error,
+        Error::InvalidCertificate(CertificateError::BadEncoding)
+    ));
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "rustls")]
+
+use core::time::Duration;
+use std::sync::Arc;
+
+use pki_types::{CertificateDer, ServerName, TrustAnchor, UnixTime};
+use rcgen::{
+    date_time_ymd, BasicConstraints, CertificateParams, CertifiedIssuer, DnType, IsCa, KeyPair,
+};
+use rustls::client::danger::ServerCertVerifier;
+use rustls::client::WebPkiServerVerifier;
+use rustls::{CertificateError, Error, RootCertStore};
+use webpki::anchor_from_trusted_cert;
+
+use webpki_roots::DistrustAfterVerifier;
+
+#[test]
+fn distrust_after() {
+    let (distrusted_ca, distrusted_anchor) = ca("Distrusted Root");
+    let (other_ca, other_anchor) = ca("Other Root");
+
+    // 2021-06-30T23:59:59Z, the end of the distrust after date.
+    let distrust_after = UnixTime::since_unix_epoch(Duration::from_secs(1_625_097_599));
+    let roots: &'static [_] = Vec::leak(vec![distrusted_anchor.clone()]);
+    let distrust_after: &'static [_] = Vec::leak(vec![(0, distrust_after)]);
+    let verifier = |anchors: &[TrustAnchor<'static>]| {
+        let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+        let inner = WebPkiServerVerifier::builder_with_provider(
+            Arc::new(RootCertStore {
+                roots: anchors.to_vec(),
+            }),
+            provider.clone(),
+        )
+        .build()
+        .unwrap();
+        DistrustAfterVerifier::with_roots(
+            inner,
+            provider.signature_verification_algorithms,
+            roots,
+            distrust_after,
+        )
+    };
+
+    let verify = |verifier: &DistrustAfterVerifier, ee: &CertificateDer<'_>| {
+        let now = UnixTime::since_unix_epoch(Duration::from_secs(1_700_000_000));
+        let name = ServerName::try_from("example.com").unwrap();
+        verifier.verify_server_cert(ee, &[], &name, &[], now)
+    };
+
+    let only_distrusted = verifier(roots);
+
+    // Certificates issued on or before the distrust after date are still trusted.
+    let ee = ee_issued_on(&distrusted_ca, (2021, 6, 30));
+    verify(&only_distrusted, &ee).unwrap();
+
+    // Certificates issued after it are not.
+    let ee = ee_issued_on(&distrusted_ca, (2021, 7, 1));
+    assert!(matches!(
+        verify(&only_distrusted, &ee),
+        Err(Error::InvalidCertificate(CertificateError::UnknownIssuer))
+    ));
+
+    // Chains anchored at roots unknown to the distrust table are left to the inner verifier.
+    let both = verifier(&[distrusted_anchor, other_anchor]);
+    let ee = ee_issued_on(&other_ca, (2021, 7, 1));
+    verify(&both, &ee).unwrap();
+}
+
+fn ca(name: &str) -> (CertifiedIssuer<'static, KeyPair>, TrustAnchor<'static>) {
+    let mut params = CertificateParams::new([]).unwrap();
+    params.distinguished_name.push(DnType::CommonName, name);
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    let ca = CertifiedIssuer::self_signed(params, KeyPair::generate().unwrap()).unwrap();
+    let anchor = anchor_from_trusted_cert(ca.der()).unwrap().to_owned();
+    (ca, anchor)
+}
+
+fn ee_issued_on(
+    issuer: &CertifiedIssuer<'static, KeyPair>,
+    (year, month, day): (i32, u8, u8),
+) -> CertificateDer<'static> {
+    let mut params = CertificateParams::new(vec!["example.com".to_string()]).unwrap();
+    params.is_ca = IsCa::NoCa;
+    params.not_before = date_time_ymd(year, month, day);
+    params.not_after = date_time_ymd(2030, 1, 1);
+    let key = KeyPair::generate().unwrap();
+    params.signed_by(&key, issuer).unwrap().into()
+}